@@ -308,6 +308,7 @@ impl AuthenticationPrompt {
             underline: None,
             strikethrough: None,
             white_space: WhiteSpace::Normal,
+            ..Default::default()
         };
         EditorElement::new(
             &self.api_key,
@@ -1737,6 +1737,7 @@ impl PromptEditor {
             underline: None,
             strikethrough: None,
             white_space: WhiteSpace::Normal,
+            ..Default::default()
         };
         EditorElement::new(
             &self.editor,
@@ -950,6 +950,7 @@ impl PromptEditor {
             underline: None,
             strikethrough: None,
             white_space: WhiteSpace::Normal,
+            ..Default::default()
         };
         EditorElement::new(
             &self.editor,
@@ -541,6 +541,7 @@ impl Render for MessageEditor {
             underline: None,
             strikethrough: None,
             white_space: WhiteSpace::Normal,
+            ..Default::default()
         };
 
         div()
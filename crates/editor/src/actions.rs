@@ -168,6 +168,7 @@ gpui::actions!(
         AcceptPartialInlineCompletion,
         AddSelectionAbove,
         AddSelectionBelow,
+        AlignCursors,
         Backspace,
         Cancel,
         CancelLanguageServerWork,
@@ -206,6 +207,7 @@ gpui::actions!(
         ExpandMacroRecursively,
         FindAllReferences,
         Fold,
+        FoldAll,
         FoldSelectedRanges,
         Format,
         GoToDefinition,
@@ -306,6 +308,7 @@ gpui::actions!(
         Transpose,
         Undo,
         UndoSelection,
+        UnfoldAll,
         UnfoldLines,
         UniqueLinesCaseInsensitive,
         UniqueLinesCaseSensitive,
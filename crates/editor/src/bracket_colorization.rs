@@ -0,0 +1,195 @@
+use std::{any::TypeId, cmp::Reverse, ops::Range};
+
+use gpui::{HighlightStyle, ViewContext};
+use multi_buffer::{Anchor, MultiBufferSnapshot};
+use theme::ActiveTheme;
+
+use crate::{Editor, EditorMode};
+
+/// The number of highlight layers used to color brackets. Brackets nested
+/// deeper than this reuse the layers from the outermost one.
+const BRACKET_COLOR_LAYERS: usize = 8;
+
+struct BracketColorHighlight<const DEPTH: usize>;
+
+fn bracket_color_highlight_id(layer: usize) -> TypeId {
+    match layer {
+        0 => TypeId::of::<BracketColorHighlight<0>>(),
+        1 => TypeId::of::<BracketColorHighlight<1>>(),
+        2 => TypeId::of::<BracketColorHighlight<2>>(),
+        3 => TypeId::of::<BracketColorHighlight<3>>(),
+        4 => TypeId::of::<BracketColorHighlight<4>>(),
+        5 => TypeId::of::<BracketColorHighlight<5>>(),
+        6 => TypeId::of::<BracketColorHighlight<6>>(),
+        _ => TypeId::of::<BracketColorHighlight<7>>(),
+    }
+}
+
+pub(super) fn refresh_bracket_colors(editor: &mut Editor, cx: &mut ViewContext<Editor>) {
+    let buffer = editor.buffer.read(cx);
+    let snapshot = buffer.snapshot(cx);
+    let accents = cx.theme().accents().0.clone();
+    let enabled = editor.mode != EditorMode::SingleLine
+        && buffer.is_singleton()
+        && !accents.is_empty()
+        && snapshot.settings_at(0, cx).colorize_brackets;
+
+    if !enabled {
+        editor.bracket_colors_task = None;
+        let mut cleared = false;
+        editor.display_map.update(cx, |map, _| {
+            for layer in 0..BRACKET_COLOR_LAYERS {
+                cleared |= map.clear_highlights(bracket_color_highlight_id(layer));
+            }
+        });
+        if cleared {
+            cx.notify();
+        }
+        return;
+    }
+
+    editor.bracket_colors_task = Some(cx.spawn(|editor, mut cx| async move {
+        let ranges_by_layer = cx
+            .background_executor()
+            .spawn(async move { bracket_ranges_by_layer(&snapshot) })
+            .await;
+
+        editor
+            .update(&mut cx, |editor, cx| {
+                editor.display_map.update(cx, |map, _| {
+                    for (layer, ranges) in ranges_by_layer.into_iter().enumerate() {
+                        map.highlight_text(
+                            bracket_color_highlight_id(layer),
+                            ranges,
+                            HighlightStyle {
+                                color: Some(accents[layer % accents.len()]),
+                                ..Default::default()
+                            },
+                        );
+                    }
+                });
+                cx.notify();
+            })
+            .ok();
+    }));
+}
+
+/// Groups the brackets of the buffer by nesting depth, modulo the number of
+/// highlight layers. The ranges of each layer are sorted by their start.
+fn bracket_ranges_by_layer(snapshot: &MultiBufferSnapshot) -> Vec<Vec<Range<Anchor>>> {
+    let mut pairs = snapshot
+        .bracket_ranges(0..snapshot.len())
+        .map(|pairs| pairs.collect::<Vec<_>>())
+        .unwrap_or_default();
+    pairs.sort_by_key(|(open, close)| (open.start, Reverse(close.end)));
+
+    let mut offsets_by_layer = vec![Vec::new(); BRACKET_COLOR_LAYERS];
+    let mut enclosing_ends = Vec::new();
+    for (open, close) in pairs {
+        while enclosing_ends.last().is_some_and(|end| *end <= open.start) {
+            enclosing_ends.pop();
+        }
+        let layer = enclosing_ends.len() % BRACKET_COLOR_LAYERS;
+        offsets_by_layer[layer].push(open);
+        offsets_by_layer[layer].push(close.clone());
+        enclosing_ends.push(close.end);
+    }
+
+    offsets_by_layer
+        .into_iter()
+        .map(|mut ranges| {
+            ranges.sort_by_key(|range| range.start);
+            ranges
+                .into_iter()
+                .map(|range| snapshot.anchor_after(range.start)..snapshot.anchor_before(range.end))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{editor_tests::init_test, test::editor_lsp_test_context::EditorLspTestContext};
+    use indoc::indoc;
+    use language::{BracketPair, BracketPairConfig, Language, LanguageConfig, LanguageMatcher};
+
+    #[gpui::test]
+    async fn test_bracket_colors_by_depth(cx: &mut gpui::TestAppContext) {
+        init_test(cx, |settings| {
+            settings.defaults.colorize_brackets = Some(true);
+        });
+
+        let mut cx = EditorLspTestContext::new(
+            Language::new(
+                LanguageConfig {
+                    name: "Rust".into(),
+                    matcher: LanguageMatcher {
+                        path_suffixes: vec!["rs".to_string()],
+                        ..Default::default()
+                    },
+                    brackets: BracketPairConfig {
+                        pairs: vec![
+                            BracketPair {
+                                start: "{".to_string(),
+                                end: "}".to_string(),
+                                close: false,
+                                surround: false,
+                                newline: true,
+                            },
+                            BracketPair {
+                                start: "(".to_string(),
+                                end: ")".to_string(),
+                                close: false,
+                                surround: false,
+                                newline: true,
+                            },
+                        ],
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                Some(tree_sitter_rust::language()),
+            )
+            .with_brackets_query(indoc! {r#"
+                ("{" @open "}" @close)
+                ("(" @open ")" @close)
+                "#})
+            .unwrap(),
+            Default::default(),
+            cx,
+        )
+        .await;
+
+        cx.set_state("fn main() { foo(bar(1)); }ˇ");
+        cx.executor().run_until_parked();
+
+        let ranges = cx.update_editor(|editor, cx| {
+            let snapshot = editor.buffer().read(cx).snapshot(cx);
+            (0..3)
+                .map(|layer| {
+                    editor
+                        .display_map
+                        .read(cx)
+                        .text_highlights(bracket_color_highlight_id(layer))
+                        .map(|(_, ranges)| {
+                            ranges
+                                .iter()
+                                .map(|range| snapshot.text_for_range(range.clone()).collect())
+                                .collect::<Vec<String>>()
+                        })
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(
+            ranges,
+            vec![
+                vec!["(".to_string(), ")".to_string(), "{".into(), "}".into()],
+                vec!["(".to_string(), ")".to_string()],
+                vec!["(".to_string(), ")".to_string()],
+            ]
+        );
+    }
+}
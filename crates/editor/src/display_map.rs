@@ -945,6 +945,10 @@ impl DisplaySnapshot {
         DisplayRow(self.block_snapshot.longest_row())
     }
 
+    pub fn tab_size(&self) -> NonZeroU32 {
+        self.tab_snapshot.tab_size
+    }
+
     pub fn starts_indent(&self, buffer_row: MultiBufferRow) -> bool {
         let max_row = self.buffer_snapshot.max_buffer_row();
         if buffer_row >= max_row {
@@ -970,6 +974,90 @@ impl DisplaySnapshot {
             .unwrap_or(false)
     }
 
+    /// Returns whether the given row opens a `region` marker or a multi-line block comment.
+    /// Only the row itself is inspected, so this is cheap enough to call for every visible row.
+    pub fn starts_marker_fold(&self, buffer_row: MultiBufferRow) -> bool {
+        let mut line = String::new();
+        self.read_buffer_line(buffer_row, &mut line);
+        let delimiters = self.comment_delimiters(buffer_row, &line);
+        marker_fold_start(&line, &delimiters).is_some()
+    }
+
+    /// Returns the end of the range folded by a `region` marker or a block comment
+    /// starting on the given row: the end of the line containing the matching
+    /// `endregion` marker or the block comment's closing delimiter. At most
+    /// [`MAX_MARKER_FOLD_ROWS`] rows are searched for the end.
+    fn marker_fold_end(&self, buffer_row: MultiBufferRow) -> Option<Point> {
+        let mut line = String::new();
+        self.read_buffer_line(buffer_row, &mut line);
+        let delimiters = self.comment_delimiters(buffer_row, &line);
+        let start = marker_fold_start(&line, &delimiters)?;
+
+        let prefixes = delimiters.prefixes();
+        let last_row = self
+            .buffer_snapshot
+            .max_buffer_row()
+            .0
+            .min(buffer_row.0.saturating_add(MAX_MARKER_FOLD_ROWS));
+        let mut depth = 0;
+        for row in buffer_row.0 + 1..=last_row {
+            let row = MultiBufferRow(row);
+            self.read_buffer_line(row, &mut line);
+            let is_end = match start {
+                MarkerFoldStart::Region => match region_marker(&line, &prefixes) {
+                    Some(RegionMarker::Start) => {
+                        depth += 1;
+                        false
+                    }
+                    Some(RegionMarker::End) if depth == 0 => true,
+                    Some(RegionMarker::End) => {
+                        depth -= 1;
+                        false
+                    }
+                    None => false,
+                },
+                MarkerFoldStart::BlockComment => delimiters
+                    .block_comment
+                    .as_ref()
+                    .map_or(false, |(_, close)| line.contains(close.as_str())),
+            };
+            if is_end {
+                return Some(Point::new(row.0, self.buffer_snapshot.line_len(row)));
+            }
+        }
+        None
+    }
+
+    fn comment_delimiters(&self, buffer_row: MultiBufferRow, line: &str) -> CommentDelimiters {
+        let indent = line.len() - line.trim_start().len();
+        let Some(scope) = self
+            .buffer_snapshot
+            .language_scope_at(Point::new(buffer_row.0, indent as u32))
+        else {
+            return CommentDelimiters::default();
+        };
+        CommentDelimiters {
+            line_comment_prefixes: scope
+                .line_comment_prefixes()
+                .iter()
+                .map(|prefix| prefix.trim().to_string())
+                .filter(|prefix| !prefix.is_empty())
+                .collect(),
+            block_comment: scope
+                .block_comment_delimiters()
+                .map(|(open, close)| (open.trim().to_string(), close.trim().to_string())),
+        }
+    }
+
+    /// Replaces the contents of `line` with the text of the given buffer row, reusing its allocation.
+    fn read_buffer_line(&self, buffer_row: MultiBufferRow, line: &mut String) {
+        line.clear();
+        line.extend(self.buffer_snapshot.text_for_range(
+            Point::new(buffer_row.0, 0)
+                ..Point::new(buffer_row.0, self.buffer_snapshot.line_len(buffer_row)),
+        ));
+    }
+
     pub fn foldable_range(
         &self,
         buffer_row: MultiBufferRow,
@@ -983,9 +1071,11 @@ impl DisplaySnapshot {
                 crease.range.to_point(&self.buffer_snapshot),
                 crease.placeholder.clone(),
             ))
-        } else if self.starts_indent(MultiBufferRow(start.row))
-            && !self.is_line_folded(MultiBufferRow(start.row))
-        {
+        } else if self.is_line_folded(buffer_row) {
+            None
+        } else if let Some(end) = self.marker_fold_end(buffer_row) {
+            Some((start..end, self.fold_placeholder.clone()))
+        } else if self.starts_indent(MultiBufferRow(start.row)) {
             let start_line_indent = self.line_indent_for_buffer_row(buffer_row);
             let max_point = self.buffer_snapshot.max_point();
             let mut end = None;
@@ -1159,6 +1249,80 @@ impl ToDisplayPoint for Anchor {
     }
 }
 
+/// The number of rows below a `region` marker or block comment opener that are searched for
+/// its end before giving up.
+const MAX_MARKER_FOLD_ROWS: u32 = 10_000;
+
+#[derive(Default)]
+struct CommentDelimiters {
+    line_comment_prefixes: Vec<String>,
+    block_comment: Option<(String, String)>,
+}
+
+impl CommentDelimiters {
+    /// The prefixes that can precede a `region` marker: line comment prefixes and the
+    /// block comment opener.
+    fn prefixes(&self) -> Vec<&str> {
+        self.line_comment_prefixes
+            .iter()
+            .chain(self.block_comment.as_ref().map(|(open, _)| open))
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MarkerFoldStart {
+    Region,
+    BlockComment,
+}
+
+fn marker_fold_start(line: &str, delimiters: &CommentDelimiters) -> Option<MarkerFoldStart> {
+    if region_marker(line, &delimiters.prefixes()) == Some(RegionMarker::Start) {
+        return Some(MarkerFoldStart::Region);
+    }
+    let (open, close) = delimiters.block_comment.as_ref()?;
+    let comment = line.trim_start().strip_prefix(open.as_str())?;
+    (!comment.contains(close.as_str())).then_some(MarkerFoldStart::BlockComment)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RegionMarker {
+    Start,
+    End,
+}
+
+/// Recognizes lines such as `// #region Name`, `// endregion` or `#region`, where the
+/// marker follows one of the given comment prefixes or is written as a `#` directive.
+fn region_marker(line: &str, comment_prefixes: &[&str]) -> Option<RegionMarker> {
+    let line = line.trim_start();
+    let directive = line.strip_prefix('#');
+    comment_prefixes
+        .iter()
+        .filter_map(|prefix| line.strip_prefix(prefix))
+        .map(|comment| {
+            let comment = comment.trim_start();
+            comment.strip_prefix('#').unwrap_or(comment)
+        })
+        .chain(directive)
+        .find_map(|marker| {
+            let is_marker = |keyword: &str| {
+                marker.strip_prefix(keyword).map_or(false, |rest| {
+                    rest.chars()
+                        .next()
+                        .map_or(true, |c| c.is_whitespace() || c == ':')
+                })
+            };
+            if is_marker("region") {
+                Some(RegionMarker::Start)
+            } else if is_marker("endregion") {
+                Some(RegionMarker::End)
+            } else {
+                None
+            }
+        })
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -2159,6 +2323,35 @@ pub mod tests {
         chunks
     }
 
+    #[test]
+    fn test_region_markers() {
+        let prefixes = ["//", "/*"];
+        assert_eq!(
+            region_marker("  // #region Imports", &prefixes),
+            Some(RegionMarker::Start)
+        );
+        assert_eq!(
+            region_marker("// region: helpers", &prefixes),
+            Some(RegionMarker::Start)
+        );
+        assert_eq!(
+            region_marker("/* #region */", &prefixes),
+            Some(RegionMarker::Start)
+        );
+        assert_eq!(region_marker("#region", &[]), Some(RegionMarker::Start));
+        assert_eq!(
+            region_marker("    // #endregion", &prefixes),
+            Some(RegionMarker::End)
+        );
+        assert_eq!(
+            region_marker("#endregion Imports", &[]),
+            Some(RegionMarker::End)
+        );
+        assert_eq!(region_marker("// regions are nested", &prefixes), None);
+        assert_eq!(region_marker("let region = 1;", &prefixes), None);
+        assert_eq!(region_marker("region", &[]), None);
+    }
+
     fn init_test(cx: &mut AppContext, f: impl Fn(&mut AllLanguageSettingsContent)) {
         let settings = SettingsStore::test(cx);
         cx.set_global(settings);
@@ -15,6 +15,7 @@
 pub mod actions;
 mod blame_entry_tooltip;
 mod blink_manager;
+mod bracket_colorization;
 mod debounced_delay;
 pub mod display_map;
 mod editor_settings;
@@ -57,10 +58,10 @@ use debounced_delay::DebouncedDelay;
 use display_map::*;
 pub use display_map::{DisplayPoint, FoldPlaceholder};
 pub use editor_settings::{CurrentLineHighlight, EditorSettings};
-use element::LineWithInvisibles;
 pub use element::{
     CursorLayout, EditorElement, HighlightedRange, HighlightedRangeLine, PointForPosition,
 };
+use element::{LineWithInvisibles, MinimapCache, StickyScrollCache};
 use futures::FutureExt;
 use fuzzy::{StringMatch, StringMatchCandidate};
 use git::blame::GitBlame;
@@ -151,7 +152,7 @@ use util::{defer, maybe, post_inc, RangeExt, ResultExt, TryFutureExt};
 use workspace::item::{ItemHandle, PreviewTabsSettings};
 use workspace::notifications::{DetachAndPromptErr, NotificationId};
 use workspace::{
-    searchable::SearchEvent, ItemNavHistory, SplitDirection, ViewId, Workspace, WorkspaceId,
+    searchable::SearchEvent, ItemId, ItemNavHistory, SplitDirection, ViewId, Workspace, WorkspaceId,
 };
 use workspace::{OpenInTerminal, OpenTerminal, TabBarSettings, Toast};
 
@@ -171,6 +172,9 @@ pub(crate) const CURSORS_VISIBLE_FOR: Duration = Duration::from_millis(2000);
 pub const CODE_ACTIONS_DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(250);
 #[doc(hidden)]
 pub const DOCUMENT_HIGHLIGHTS_DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(75);
+#[doc(hidden)]
+pub const SERIALIZE_FOLDS_DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(500);
+const FOLD_FINGERPRINT_LEN: usize = 32;
 
 pub(crate) const FORMAT_TIMEOUT: Duration = Duration::from_secs(2);
 
@@ -467,6 +471,7 @@ pub struct Editor {
     /// typing enters text into each of them, even the ones that aren't focused.
     pub(crate) show_cursor_when_unfocused: bool,
     columnar_selection_tail: Option<Anchor>,
+    pub(crate) selection_drag: Option<SelectionDrag>,
     add_selections_state: Option<AddSelectionsState>,
     select_next_state: Option<SelectNextState>,
     select_prev_state: Option<SelectNextState>,
@@ -499,6 +504,8 @@ pub struct Editor {
     background_highlights: TreeMap<TypeId, BackgroundHighlight>,
     gutter_highlights: TreeMap<TypeId, GutterHighlight>,
     scrollbar_marker_state: ScrollbarMarkerState,
+    minimap_cache: Option<MinimapCache>,
+    sticky_scroll_cache: Option<StickyScrollCache>,
     active_indent_guides_state: ActiveIndentGuidesState,
     nav_history: Option<ItemNavHistory>,
     context_menu: RwLock<Option<ContextMenu>>,
@@ -512,6 +519,7 @@ pub struct Editor {
     available_code_actions: Option<(Location, Arc<[CodeAction]>)>,
     code_actions_task: Option<Task<()>>,
     document_highlights_task: Option<Task<()>>,
+    serialize_folds_task: Option<Task<()>>,
     linked_editing_range_task: Option<Task<Option<()>>>,
     linked_edit_ranges: linked_editing_ranges::LinkedEditingRanges,
     pending_rename: Option<RenameState>,
@@ -563,6 +571,7 @@ pub struct Editor {
     expect_bounds_change: Option<Bounds<Pixels>>,
     tasks: BTreeMap<(BufferId, BufferRow), RunnableTasks>,
     tasks_update_task: Option<Task<()>>,
+    bracket_colors_task: Option<Task<()>>,
     previous_search_ranges: Option<Arc<[Range<Anchor>]>>,
     file_header_size: u8,
     breadcrumb_header: Option<String>,
@@ -634,6 +643,39 @@ pub struct RemoteSelection {
     pub user_name: Option<SharedString>,
 }
 
+/// A drag of the selected text, started by pressing the mouse inside a selection.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SelectionDrag {
+    /// Where the text will be dropped, once the mouse has moved.
+    pub drop_position: Option<Anchor>,
+    /// Whether this only previews the drop of a [`DraggedSelection`], rather than dragging
+    /// this editor's own selections.
+    pub from_drag: bool,
+}
+
+/// Selected text being dragged between editors, see [`Editor::hand_off_selection_drag`].
+pub(crate) struct DraggedSelection {
+    source: WeakView<Editor>,
+    ranges: Vec<Range<Anchor>>,
+    text: String,
+}
+
+struct DraggedSelectionPreview {
+    text: SharedString,
+}
+
+impl Render for DraggedSelectionPreview {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let colors = cx.theme().colors();
+        div()
+            .px_1()
+            .rounded_sm()
+            .bg(colors.elevated_surface_background)
+            .text_color(colors.text)
+            .child(self.text.clone())
+    }
+}
+
 #[derive(Clone, Debug)]
 struct SelectionHistoryEntry {
     selections: Arc<[Selection<Anchor>]>,
@@ -1789,6 +1831,7 @@ impl Editor {
             selections,
             scroll_manager: ScrollManager::new(cx),
             columnar_selection_tail: None,
+            selection_drag: None,
             add_selections_state: None,
             select_next_state: None,
             select_prev_state: None,
@@ -1819,6 +1862,8 @@ impl Editor {
             background_highlights: Default::default(),
             gutter_highlights: TreeMap::default(),
             scrollbar_marker_state: ScrollbarMarkerState::default(),
+            minimap_cache: None,
+            sticky_scroll_cache: None,
             active_indent_guides_state: ActiveIndentGuidesState::default(),
             nav_history: None,
             context_menu: RwLock::new(None),
@@ -1833,6 +1878,7 @@ impl Editor {
             available_code_actions: Default::default(),
             code_actions_task: Default::default(),
             document_highlights_task: Default::default(),
+            serialize_folds_task: None,
             linked_editing_range_task: Default::default(),
             pending_rename: Default::default(),
             searchable: true,
@@ -1898,11 +1944,13 @@ impl Editor {
                 }),
             ],
             tasks_update_task: None,
+            bracket_colors_task: None,
             linked_edit_ranges: Default::default(),
             previous_search_ranges: None,
             breadcrumb_header: None,
         };
         this.tasks_update_task = Some(this.refresh_runnables(cx));
+        bracket_colorization::refresh_bracket_colors(&mut this, cx);
         this._subscriptions.extend(project_subscriptions);
 
         this.end_selection(cx);
@@ -2858,7 +2906,243 @@ impl Editor {
         self.selections.pending_anchor().is_some() || self.columnar_selection_tail.is_some()
     }
 
+    /// Starts dragging the selected text if the position lies within a non-empty selection.
+    pub(crate) fn begin_selection_drag(
+        &mut self,
+        position: DisplayPoint,
+        cx: &mut ViewContext<Self>,
+    ) -> bool {
+        if self.read_only(cx) {
+            return false;
+        }
+
+        let display_map = self.display_map.update(cx, |map, cx| map.snapshot(cx));
+        let offset = position.to_offset(&display_map, Bias::Left);
+        let within_selection = self
+            .selections
+            .all::<usize>(cx)
+            .iter()
+            .any(|selection| selection.start <= offset && offset < selection.end);
+        if within_selection {
+            self.selection_drag = Some(SelectionDrag::default());
+        }
+        within_selection
+    }
+
+    pub(crate) fn update_selection_drag(
+        &mut self,
+        position: DisplayPoint,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let display_map = self.display_map.update(cx, |map, cx| map.snapshot(cx));
+        let drop_position = display_map
+            .buffer_snapshot
+            .anchor_before(position.to_offset(&display_map, Bias::Left));
+        let selection_drag = self.selection_drag.get_or_insert_with(|| SelectionDrag {
+            drop_position: None,
+            from_drag: true,
+        });
+        selection_drag.drop_position = Some(drop_position);
+        cx.notify();
+    }
+
+    /// Hands the selection drag over to gpui once the mouse leaves this editor, so that the
+    /// text can be dropped into another editor.
+    pub(crate) fn hand_off_selection_drag(&mut self, cx: &mut ViewContext<Self>) {
+        if self.selection_drag.take().is_none() {
+            return;
+        }
+        cx.notify();
+
+        let buffer = self.buffer.read(cx).snapshot(cx);
+        let ranges = self
+            .selections
+            .all::<usize>(cx)
+            .into_iter()
+            .filter(|selection| !selection.is_empty())
+            .map(|selection| {
+                buffer.anchor_after(selection.start)..buffer.anchor_before(selection.end)
+            })
+            .collect::<Vec<_>>();
+        if ranges.is_empty() {
+            return;
+        }
+
+        let text = ranges
+            .iter()
+            .map(|range| buffer.text_for_range(range.clone()).collect::<String>())
+            .join("\n");
+        let preview = cx.new_view(|_| DraggedSelectionPreview {
+            text: text.lines().next().unwrap_or_default().to_string().into(),
+        });
+        cx.start_drag(
+            DraggedSelection {
+                source: cx.view().downgrade(),
+                ranges,
+                text,
+            },
+            preview.into(),
+            point(px(-8.), px(-8.)),
+        );
+    }
+
+    /// Clears the drop preview of a selection dragged in from another editor.
+    pub(crate) fn clear_selection_drop_preview(&mut self, cx: &mut ViewContext<Self>) {
+        if self
+            .selection_drag
+            .as_ref()
+            .map_or(false, |selection_drag| selection_drag.from_drag)
+        {
+            self.selection_drag = None;
+            cx.notify();
+        }
+    }
+
+    /// Drops the dragged text at the given position, moving it unless `copy` is true.
+    /// When the mouse never moved, this places a cursor at the position instead.
+    pub(crate) fn end_selection_drag(
+        &mut self,
+        position: DisplayPoint,
+        copy: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(selection_drag) = self.selection_drag.take() else {
+            return;
+        };
+        cx.notify();
+
+        if selection_drag.drop_position.is_none() {
+            self.select(
+                SelectPhase::Begin {
+                    position,
+                    add: false,
+                    click_count: 1,
+                },
+                cx,
+            );
+            self.select(SelectPhase::End, cx);
+            return;
+        }
+
+        let ranges = self
+            .selections
+            .all::<usize>(cx)
+            .into_iter()
+            .filter(|selection| !selection.is_empty())
+            .map(|selection| selection.range())
+            .collect::<Vec<_>>();
+        self.move_text(ranges, position, copy, cx);
+    }
+
+    /// Drops text dragged out of an editor, which may be this one, at the given position.
+    pub(crate) fn drop_selection(
+        &mut self,
+        dragged: DraggedSelection,
+        position: DisplayPoint,
+        copy: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.selection_drag = None;
+        cx.notify();
+        if self.read_only(cx) {
+            return;
+        }
+
+        let is_source = dragged
+            .source
+            .upgrade()
+            .map_or(false, |source| source.entity_id() == cx.entity_id());
+        if is_source {
+            let buffer = self.buffer.read(cx).snapshot(cx);
+            let ranges = dragged
+                .ranges
+                .iter()
+                .map(|range| range.start.to_offset(&buffer)..range.end.to_offset(&buffer))
+                .collect();
+            self.move_text(ranges, position, copy, cx);
+            return;
+        }
+
+        let display_map = self.display_map.update(cx, |map, cx| map.snapshot(cx));
+        let drop_offset = position.to_offset(&display_map, Bias::Left);
+        let buffer = &display_map.buffer_snapshot;
+        let dropped_range = buffer.anchor_before(drop_offset)..buffer.anchor_after(drop_offset);
+        self.transact(cx, |this, cx| {
+            this.buffer.update(cx, |buffer, cx| {
+                buffer.edit([(drop_offset..drop_offset, dragged.text)], None, cx)
+            });
+            this.change_selections(Some(Autoscroll::fit()), cx, |s| {
+                s.select_anchor_ranges([dropped_range])
+            });
+        });
+        cx.focus(&self.focus_handle);
+
+        if !copy {
+            if let Some(source) = dragged.source.upgrade() {
+                source.update(cx, |source, cx| {
+                    source.transact(cx, |source, cx| {
+                        source.buffer.update(cx, |buffer, cx| {
+                            buffer.edit(
+                                dragged.ranges.into_iter().map(|range| (range, "")),
+                                None,
+                                cx,
+                            )
+                        });
+                    });
+                });
+            }
+        }
+    }
+
+    /// Moves (or copies) the text in the given ranges to the given position, as a single
+    /// transaction. Nothing happens when the position lies within one of the ranges.
+    fn move_text(
+        &mut self,
+        ranges: Vec<Range<usize>>,
+        position: DisplayPoint,
+        copy: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let display_map = self.display_map.update(cx, |map, cx| map.snapshot(cx));
+        let buffer = &display_map.buffer_snapshot;
+        let drop_offset = position.to_offset(&display_map, Bias::Left);
+        if ranges.is_empty()
+            || ranges
+                .iter()
+                .any(|range| range.start <= drop_offset && drop_offset <= range.end)
+        {
+            return;
+        }
+
+        let text = ranges
+            .iter()
+            .map(|range| buffer.text_for_range(range.clone()).collect::<String>())
+            .join("\n");
+        let dropped_range = buffer.anchor_before(drop_offset)..buffer.anchor_after(drop_offset);
+
+        let mut edits = Vec::new();
+        if !copy {
+            edits.extend(ranges.into_iter().map(|range| (range, String::new())));
+        }
+        edits.push((drop_offset..drop_offset, text));
+        edits.sort_by_key(|(range, _)| range.start);
+
+        self.transact(cx, |this, cx| {
+            this.buffer
+                .update(cx, |buffer, cx| buffer.edit(edits, None, cx));
+            this.change_selections(Some(Autoscroll::fit()), cx, |s| {
+                s.select_anchor_ranges([dropped_range])
+            });
+        });
+    }
+
     pub fn cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
+        let cancelled_selection_drag = self.selection_drag.take().is_some();
+        if cancelled_selection_drag || cx.take_active_drag::<DraggedSelection>().is_some() {
+            cx.notify();
+            return;
+        }
+
         self.clear_expanded_diff_hunks(cx);
         if self.dismiss_menus_and_popups(true, cx) {
             return;
@@ -4174,6 +4458,43 @@ impl Editor {
         self.completion_tasks.push((id, task));
     }
 
+    /// Returns the value of the snippet variable with the given name, such as `TM_FILENAME`,
+    /// at the given position in the buffer.
+    fn snippet_variable(
+        name: &str,
+        buffer: &Buffer,
+        position: Point,
+        cx: &AppContext,
+    ) -> Option<String> {
+        let path = || {
+            let file = buffer.file()?;
+            Some(
+                file.as_local()
+                    .map(|file| file.abs_path(cx))
+                    .unwrap_or_else(|| file.full_path(cx)),
+            )
+        };
+
+        match name {
+            "TM_LINE_INDEX" => Some(position.row.to_string()),
+            "TM_LINE_NUMBER" => Some((position.row + 1).to_string()),
+            "TM_CURRENT_LINE" => Some(
+                buffer
+                    .text_for_range(
+                        Point::new(position.row, 0)
+                            ..Point::new(position.row, buffer.line_len(position.row)),
+                    )
+                    .collect(),
+            ),
+            "TM_FILENAME" => Some(buffer.file()?.file_name(cx).to_string_lossy().into_owned()),
+            "TM_FILENAME_BASE" => Some(path()?.file_stem()?.to_string_lossy().into_owned()),
+            "TM_DIRECTORY" => Some(path()?.parent()?.to_string_lossy().into_owned()),
+            "TM_FILEPATH" => Some(path()?.to_string_lossy().into_owned()),
+            "CLIPBOARD" => Some(cx.read_from_clipboard()?.text().clone()),
+            _ => None,
+        }
+    }
+
     pub fn confirm_completion(
         &mut self,
         action: &ConfirmCompletion,
@@ -4199,7 +4520,14 @@ impl Editor {
         let text;
 
         if completion.is_snippet() {
-            snippet = Some(Snippet::parse(&completion.new_text).log_err()?);
+            let buffer = buffer_handle.read(cx);
+            let position = language::ToPoint::to_point(&completion.old_range.start, buffer);
+            snippet = Some(
+                Snippet::parse_with_variables(&completion.new_text, |name| {
+                    Self::snippet_variable(name, buffer, position, cx)
+                })
+                .log_err()?,
+            );
             text = snippet.as_ref().unwrap().text.clone();
         } else {
             snippet = None;
@@ -5507,6 +5835,80 @@ impl Editor {
         });
     }
 
+    /// Inserts spaces before the selections so that they all start at the same column.
+    /// When rows contain several selections, the n-th selection of every row is aligned
+    /// with the n-th selection of the other rows.
+    pub fn align_cursors(&mut self, _: &AlignCursors, cx: &mut ViewContext<Self>) {
+        if self.read_only(cx) {
+            return;
+        }
+
+        let mut selections = self.selections.all::<Point>(cx);
+        let snapshot = self.buffer.read(cx).snapshot(cx);
+
+        let mut rows: Vec<(u32, Vec<usize>)> = Vec::new();
+        for (ix, selection) in selections.iter().enumerate() {
+            match rows.last_mut() {
+                Some((row, selection_ixs)) if *row == selection.start.row => selection_ixs.push(ix),
+                _ => rows.push((selection.start.row, vec![ix])),
+            }
+        }
+        if rows.len() < 2 {
+            return;
+        }
+
+        let mut edits = Vec::new();
+        let mut row_deltas = vec![0; rows.len()];
+        let max_selections_per_row = rows.iter().map(|(_, ixs)| ixs.len()).max().unwrap_or(0);
+        for column_ix in 0..max_selections_per_row {
+            let columns = rows
+                .iter()
+                .enumerate()
+                .filter_map(|(row_ix, (row, selection_ixs))| {
+                    let selection_ix = *selection_ixs.get(column_ix)?;
+                    let start = selections[selection_ix].start;
+                    let char_column = snapshot
+                        .text_for_range(Point::new(*row, 0)..start)
+                        .flat_map(str::chars)
+                        .count() as u32
+                        + row_deltas[row_ix];
+                    Some((row_ix, selection_ix, char_column))
+                })
+                .collect::<Vec<_>>();
+            let Some(target_column) = columns.iter().map(|(_, _, column)| *column).max() else {
+                continue;
+            };
+
+            for (row_ix, selection_ix, char_column) in columns {
+                let selection = &mut selections[selection_ix];
+                let padding = target_column - char_column;
+                if padding > 0 {
+                    edits.push((
+                        selection.start..selection.start,
+                        " ".repeat(padding as usize),
+                    ));
+                }
+                row_deltas[row_ix] += padding;
+
+                if selection.end.row == selection.start.row {
+                    selection.end.column += row_deltas[row_ix];
+                }
+                selection.start.column += row_deltas[row_ix];
+            }
+        }
+
+        if edits.is_empty() {
+            return;
+        }
+        edits.sort_by_key(|(range, _)| range.start);
+
+        self.transact(cx, |this, cx| {
+            this.buffer
+                .update(cx, |buffer, cx| buffer.edit(edits, None, cx));
+            this.change_selections(Some(Autoscroll::fit()), cx, |s| s.select(selections));
+        });
+    }
+
     pub fn indent(&mut self, _: &Indent, cx: &mut ViewContext<Self>) {
         if self.read_only(cx) {
             return;
@@ -9947,6 +10349,26 @@ impl Editor {
         self.fold_ranges(fold_ranges, true, cx);
     }
 
+    pub fn fold_all(&mut self, _: &actions::FoldAll, cx: &mut ViewContext<Self>) {
+        let display_map = self.display_map.update(cx, |map, cx| map.snapshot(cx));
+        let max_row = display_map.buffer_snapshot.max_buffer_row();
+
+        let mut fold_ranges = Vec::new();
+        let mut row = 0;
+        while row <= max_row.0 {
+            if let Some((foldable_range, fold_text)) =
+                display_map.foldable_range(MultiBufferRow(row))
+            {
+                row = foldable_range.end.row + 1;
+                fold_ranges.push((foldable_range, fold_text));
+            } else {
+                row += 1;
+            }
+        }
+
+        self.fold_ranges(fold_ranges, true, cx);
+    }
+
     pub fn fold_at(&mut self, fold_at: &FoldAt, cx: &mut ViewContext<Self>) {
         let buffer_row = fold_at.buffer_row;
         let display_map = self.display_map.update(cx, |map, cx| map.snapshot(cx));
@@ -9981,6 +10403,11 @@ impl Editor {
         self.unfold_ranges(ranges, true, true, cx);
     }
 
+    pub fn unfold_all(&mut self, _: &actions::UnfoldAll, cx: &mut ViewContext<Self>) {
+        let buffer_len = self.buffer.read(cx).len(cx);
+        self.unfold_ranges([0..buffer_len], true, true, cx);
+    }
+
     pub fn unfold_at(&mut self, unfold_at: &UnfoldAt, cx: &mut ViewContext<Self>) {
         let display_map = self.display_map.update(cx, |map, cx| map.snapshot(cx));
 
@@ -10065,6 +10492,7 @@ impl Editor {
             }
 
             self.scrollbar_marker_state.dirty = true;
+            self.serialize_folds(cx);
         }
     }
 
@@ -10100,9 +10528,88 @@ impl Editor {
             cx.notify();
             self.scrollbar_marker_state.dirty = true;
             self.active_indent_guides_state.dirty = true;
+            self.serialize_folds(cx);
         }
     }
 
+    /// Stores the folds of a singleton buffer, so that they can be restored when the
+    /// workspace is reopened. Writes are debounced, so that folding or unfolding many
+    /// ranges in a row only writes the final set of folds.
+    fn serialize_folds(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(workspace_id) = self.workspace.as_ref().and_then(|workspace| workspace.1) else {
+            return;
+        };
+        if !self.buffer.read(cx).is_singleton() {
+            return;
+        }
+
+        let item_id = cx.view().entity_id().as_u64() as ItemId;
+        self.serialize_folds_task = Some(cx.spawn(|editor, mut cx| async move {
+            cx.background_executor()
+                .timer(SERIALIZE_FOLDS_DEBOUNCE_TIMEOUT)
+                .await;
+            let Some(folds) = editor
+                .update(&mut cx, |editor, cx| {
+                    let display_map = editor.display_map.update(cx, |map, cx| map.snapshot(cx));
+                    let buffer = &display_map.buffer_snapshot;
+                    display_map
+                        .folds_in_range(0..buffer.len())
+                        .map(|fold| {
+                            let range = fold.range.start.to_offset(buffer)
+                                ..fold.range.end.to_offset(buffer);
+                            let (start_fingerprint, end_fingerprint) =
+                                fold_fingerprints(buffer, range.clone());
+                            (range.start, range.end, start_fingerprint, end_fingerprint)
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .ok()
+            else {
+                return;
+            };
+
+            persistence::DB
+                .save_folds(item_id, workspace_id, folds)
+                .await
+                .log_err();
+        }));
+    }
+
+    /// Restores the folds stored by [`Self::serialize_folds`], skipping any fold whose
+    /// text no longer matches the text that was folded.
+    pub(crate) fn read_folds_from_db(
+        &mut self,
+        item_id: ItemId,
+        workspace_id: WorkspaceId,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(folds) = persistence::DB.get_folds(item_id, workspace_id).log_err() else {
+            return;
+        };
+
+        let display_map = self.display_map.update(cx, |map, cx| map.snapshot(cx));
+        let buffer = &display_map.buffer_snapshot;
+        let fold_ranges = folds
+            .into_iter()
+            .filter_map(|(start, end, start_fingerprint, end_fingerprint)| {
+                if start >= end
+                    || end > buffer.len()
+                    || buffer.clip_offset(start, Bias::Left) != start
+                    || buffer.clip_offset(end, Bias::Right) != end
+                {
+                    return None;
+                }
+                let fingerprints = fold_fingerprints(buffer, start..end);
+                (Some(fingerprints.0) == start_fingerprint
+                    && Some(fingerprints.1) == end_fingerprint)
+                    .then(|| (start..end, display_map.fold_placeholder.clone()))
+            })
+            .collect::<Vec<_>>();
+        self.fold_ranges(fold_ranges, false, cx);
+        // The restored folds are already stored, so there's no need to write them back.
+        self.serialize_folds_task = None;
+    }
+
     pub fn set_gutter_hovered(&mut self, hovered: bool, cx: &mut ViewContext<Self>) {
         if hovered != self.gutter_hovered {
             self.gutter_hovered = hovered;
@@ -11192,11 +11699,13 @@ impl Editor {
             }
             multi_buffer::Event::Reparsed(buffer_id) => {
                 self.tasks_update_task = Some(self.refresh_runnables(cx));
+                bracket_colorization::refresh_bracket_colors(self, cx);
 
                 cx.emit(EditorEvent::Reparsed(*buffer_id));
             }
             multi_buffer::Event::LanguageChanged(buffer_id) => {
                 linked_editing_ranges::refresh_linked_ranges(self, cx);
+                bracket_colorization::refresh_bracket_colors(self, cx);
                 cx.emit(EditorEvent::Reparsed(*buffer_id));
                 cx.notify();
             }
@@ -11231,6 +11740,7 @@ impl Editor {
     fn settings_changed(&mut self, cx: &mut ViewContext<Self>) {
         self.tasks_update_task = Some(self.refresh_runnables(cx));
         self.refresh_inline_completion(true, cx);
+        bracket_colorization::refresh_bracket_colors(self, cx);
         self.refresh_inlay_hints(
             InlayHintRefreshReason::SettingsChange(inlay_hint_settings(
                 self.selections.newest_anchor().head(),
@@ -12016,6 +12526,26 @@ fn ending_row(next_selection: &Selection<Point>, display_map: &DisplaySnapshot)
     }
 }
 
+/// Returns the text at the start and at the end of the given range, used to check whether a
+/// stored fold still covers the same text.
+fn fold_fingerprints(buffer: &MultiBufferSnapshot, range: Range<usize>) -> (String, String) {
+    let start_end = buffer.clip_offset(
+        (range.start + FOLD_FINGERPRINT_LEN).min(range.end),
+        Bias::Left,
+    );
+    let end_start = buffer.clip_offset(
+        range
+            .end
+            .saturating_sub(FOLD_FINGERPRINT_LEN)
+            .max(range.start),
+        Bias::Right,
+    );
+    (
+        buffer.text_for_range(range.start..start_end).collect(),
+        buffer.text_for_range(end_start..range.end).collect(),
+    )
+}
+
 impl EditorSnapshot {
     pub fn remote_selections_in_range<'a>(
         &'a self,
@@ -12167,7 +12697,8 @@ impl EditorSnapshot {
                 cx,
             ))
         } else if folded
-            || (self.starts_indent(buffer_row) && (row_contains_cursor || self.gutter_hovered))
+            || ((self.starts_indent(buffer_row) || self.starts_marker_fold(buffer_row))
+                && (row_contains_cursor || self.gutter_hovered))
         {
             Some(
                 Disclosure::new(("indent-fold-indicator", buffer_row.0), !folded)
@@ -12282,6 +12813,7 @@ impl Render for Editor {
                 underline: None,
                 strikethrough: None,
                 white_space: WhiteSpace::Normal,
+                ..Default::default()
             },
             EditorMode::Full => TextStyle {
                 color: cx.theme().colors().editor_foreground,
@@ -12295,6 +12827,7 @@ impl Render for Editor {
                 underline: None,
                 strikethrough: None,
                 white_space: WhiteSpace::Normal,
+                ..Default::default()
             },
         };
 
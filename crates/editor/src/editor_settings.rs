@@ -15,6 +15,9 @@ pub struct EditorSettings {
     pub toolbar: Toolbar,
     pub scrollbar: Scrollbar,
     pub gutter: Gutter,
+    pub sticky_scroll: StickyScroll,
+    pub minimap: Minimap,
+    pub show_soft_wrap_indicators: bool,
     pub scroll_beyond_last_line: ScrollBeyondLastLine,
     pub vertical_scroll_margin: f32,
     pub scroll_sensitivity: f32,
@@ -103,6 +106,18 @@ pub struct Gutter {
     pub folds: bool,
 }
 
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct StickyScroll {
+    pub enabled: bool,
+    pub max_lines: usize,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct Minimap {
+    pub show: bool,
+    pub width: f32,
+}
+
 /// When to show the scrollbar in the editor.
 ///
 /// Default: auto
@@ -189,6 +204,14 @@ pub struct EditorSettingsContent {
     pub scrollbar: Option<ScrollbarContent>,
     /// Gutter related settings
     pub gutter: Option<GutterContent>,
+    /// Sticky scroll related settings
+    pub sticky_scroll: Option<StickyScrollContent>,
+    /// Minimap related settings
+    pub minimap: Option<MinimapContent>,
+    /// Whether to show a glyph at the end of rows that are soft wrapped.
+    ///
+    /// Default: false
+    pub show_soft_wrap_indicators: Option<bool>,
     /// Whether the editor will scroll beyond the last line.
     ///
     /// Default: one_page
@@ -318,6 +341,33 @@ pub struct GutterContent {
     pub folds: Option<bool>,
 }
 
+/// Sticky scroll related settings
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct StickyScrollContent {
+    /// Whether to pin the lines of the scopes enclosing the top of the viewport
+    /// to the top of the editor.
+    ///
+    /// Default: false
+    pub enabled: Option<bool>,
+    /// The maximum number of scope lines to pin.
+    ///
+    /// Default: 5
+    pub max_lines: Option<usize>,
+}
+
+/// Minimap related settings
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct MinimapContent {
+    /// Whether to show a zoomed-out overview of the buffer next to the scrollbar.
+    ///
+    /// Default: false
+    pub show: Option<bool>,
+    /// The width of the minimap, in pixels.
+    ///
+    /// Default: 100
+    pub width: Option<f32>,
+}
+
 impl Settings for EditorSettings {
     const KEY: Option<&'static str> = None;
 
@@ -10,7 +10,7 @@ use crate::{
 };
 use futures::StreamExt;
 use gpui::{
-    div, SemanticVersion, TestAppContext, UpdateGlobal, VisualTestContext, WindowBounds,
+    div, Modifiers, SemanticVersion, TestAppContext, UpdateGlobal, VisualTestContext, WindowBounds,
     WindowOptions,
 };
 use indoc::indoc;
@@ -912,6 +912,131 @@ fn test_fold_action(cx: &mut TestAppContext) {
     });
 }
 
+#[gpui::test]
+fn test_fold_all_and_unfold_all(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+
+    let text = "
+        fn a() {
+            1
+        }
+
+        fn b() {
+            2
+        }
+    "
+    .unindent();
+    let view = cx.add_window(|cx| {
+        let buffer = MultiBuffer::build_simple(&text, cx);
+        build_editor(buffer.clone(), cx)
+    });
+
+    _ = view.update(cx, |view, cx| {
+        view.fold_all(&actions::FoldAll, cx);
+        assert_eq!(
+            view.display_text(cx),
+            "
+                fn a() {⋯
+                }
+
+                fn b() {⋯
+                }
+            "
+            .unindent(),
+        );
+
+        view.unfold_all(&actions::UnfoldAll, cx);
+        assert_eq!(view.display_text(cx), text);
+    });
+}
+
+#[gpui::test]
+async fn test_fold_persistence(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+    cx.executor().allow_parking();
+
+    let fs = FakeFs::new(cx.executor());
+    let project = Project::test(fs, [], cx).await;
+    let workspace = cx.add_window(|cx| Workspace::test_new(project, cx));
+    let weak_workspace = workspace
+        .update(cx, |workspace, _| workspace.weak_handle())
+        .unwrap();
+    let workspace_id = workspace::WORKSPACE_DB.next_id().await.unwrap();
+    let build_editor_in_workspace = |text: &str, cx: &mut TestAppContext| {
+        workspace
+            .update(cx, |_, cx| {
+                cx.new_view(|cx| {
+                    let mut editor = build_editor(MultiBuffer::build_simple(text, cx), cx);
+                    editor.workspace = Some((weak_workspace.clone(), Some(workspace_id)));
+                    editor
+                })
+            })
+            .unwrap()
+    };
+
+    let text = "fn a() {\n    1\n}\n\nfn b() {\n    2\n}\n";
+    let editor = build_editor_in_workspace(text, cx);
+    let item_id = editor.entity_id().as_u64() as ItemId;
+    persistence::DB
+        .save_path(item_id, workspace_id, "/a.rs".into())
+        .await
+        .unwrap();
+
+    let folded_text = workspace
+        .update(cx, |_, cx| {
+            editor.update(cx, |editor, cx| {
+                editor.fold_all(&actions::FoldAll, cx);
+                editor.display_text(cx)
+            })
+        })
+        .unwrap();
+    assert_eq!(folded_text, "fn a() {⋯\n}\n\nfn b() {⋯\n}\n");
+
+    // Folds are written once the debounce timeout elapses.
+    cx.executor()
+        .advance_clock(SERIALIZE_FOLDS_DEBOUNCE_TIMEOUT);
+    let serialize_folds = workspace
+        .update(cx, |_, cx| {
+            editor.update(cx, |editor, _| editor.serialize_folds_task.take())
+        })
+        .unwrap()
+        .expect("folding should schedule serialization");
+    serialize_folds.await;
+    assert_eq!(
+        persistence::DB
+            .get_folds(item_id, workspace_id)
+            .unwrap()
+            .len(),
+        2
+    );
+
+    // Restoring the folds doesn't write them back.
+    let restored = build_editor_in_workspace(text, cx);
+    workspace
+        .update(cx, |_, cx| {
+            restored.update(cx, |editor, cx| {
+                editor.read_folds_from_db(item_id, workspace_id, cx);
+                assert_eq!(editor.display_text(cx), folded_text);
+                assert!(editor.serialize_folds_task.is_none());
+            })
+        })
+        .unwrap();
+
+    // Folds whose text changed since they were stored are dropped.
+    let changed = build_editor_in_workspace("fn a() {\n    9\n}\n\nfn b() {\n    2\n}\n", cx);
+    workspace
+        .update(cx, |_, cx| {
+            changed.update(cx, |editor, cx| {
+                editor.read_folds_from_db(item_id, workspace_id, cx);
+                assert_eq!(
+                    editor.display_text(cx),
+                    "fn a() {\n    9\n}\n\nfn b() {⋯\n}\n"
+                );
+            })
+        })
+        .unwrap();
+}
+
 #[gpui::test]
 fn test_fold_action_whitespace_sensitive_language(cx: &mut TestAppContext) {
     init_test(cx, |_| {});
@@ -1952,6 +2077,128 @@ async fn test_autoscroll(cx: &mut gpui::TestAppContext) {
     });
 }
 
+#[gpui::test]
+async fn test_sticky_scroll(cx: &mut gpui::TestAppContext) {
+    init_test(cx, |_| {});
+    cx.update(|cx| {
+        cx.update_global::<SettingsStore, _>(|settings, cx| {
+            settings.update_user_settings::<EditorSettings>(cx, |settings| {
+                settings.sticky_scroll = Some(editor_settings::StickyScrollContent {
+                    enabled: Some(true),
+                    max_lines: None,
+                });
+            });
+        });
+    });
+
+    let language = Arc::new(
+        Language::new(
+            LanguageConfig::default(),
+            Some(tree_sitter_rust::language()),
+        )
+        .with_outline_query(
+            r#"
+            (impl_item "impl" @context type: (_) @name) @item
+            (function_item "fn" @context name: (_) @name) @item
+            "#,
+        )
+        .unwrap(),
+    );
+    let mut cx = EditorTestContext::new(cx).await;
+    cx.update_buffer(|buffer, cx| buffer.set_language(Some(language), cx));
+    let line_height = cx.update_editor(|editor, cx| {
+        editor.set_vertical_scroll_margin(0, cx);
+        editor
+            .style()
+            .unwrap()
+            .text
+            .line_height_in_pixels(cx.rem_size())
+    });
+    let window = cx.window;
+    cx.simulate_window_resize(window, size(px(1000.), 6. * line_height));
+    cx.set_state(indoc! {"
+        ˇimpl Foo {
+            fn bar() {
+                one();
+                two();
+                three();
+                four();
+                five();
+                six();
+                seven();
+                eight();
+            }
+        }
+    "});
+    cx.run_until_parked();
+
+    let pinned_headers = |cx: &mut EditorTestContext| {
+        cx.update(|cx| cx.draw());
+        cx.update_editor(|editor, _| {
+            editor
+                .sticky_scroll_cache
+                .as_ref()
+                .map(|cache| {
+                    cache
+                        .symbols
+                        .iter()
+                        .map(|symbol| symbol.text.clone())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        })
+    };
+
+    // Scrolling into the nested function pins the enclosing impl and function.
+    cx.update_editor(|editor, cx| {
+        editor.set_scroll_position(gpui::Point::new(0., 5.), cx);
+    });
+    assert_eq!(pinned_headers(&mut cx), ["impl Foo", "fn bar"]);
+
+    // The headers are the ones around the first row left visible below them, not the row
+    // they cover.
+    cx.update_editor(|editor, cx| {
+        editor.set_scroll_position(gpui::Point::new(0., 1.), cx);
+    });
+    assert_eq!(pinned_headers(&mut cx), ["impl Foo", "fn bar"]);
+
+    cx.update_editor(|editor, cx| {
+        editor.set_scroll_position(gpui::Point::new(0., 5.), cx);
+    });
+    cx.update(|cx| cx.draw());
+
+    // Clicking a header jumps to its symbol, keeping it where the header was.
+    let position = point(px(500.), line_height * 1.5);
+    cx.simulate_mouse_down(position, MouseButton::Left, Modifiers::default());
+    cx.simulate_mouse_up(position, MouseButton::Left, Modifiers::default());
+    cx.update(|cx| cx.draw());
+    cx.update_editor(|editor, cx| {
+        assert_eq!(
+            editor.selections.newest::<Point>(cx).range(),
+            Point::new(1, 4)..Point::new(1, 4)
+        );
+        assert_eq!(
+            editor.snapshot(cx).scroll_position(),
+            gpui::Point::new(0., 0.)
+        );
+    });
+
+    // Autoscrolling up to a row keeps it below the pinned headers.
+    cx.update_editor(|editor, cx| {
+        editor.set_scroll_position(gpui::Point::new(0., 6.), cx);
+        editor.change_selections(Some(Autoscroll::fit()), cx, |selections| {
+            selections.select_ranges([Point::new(5, 0)..Point::new(5, 0)]);
+        });
+    });
+    cx.update(|cx| cx.draw());
+    cx.update_editor(|editor, cx| {
+        assert_eq!(
+            editor.snapshot(cx).scroll_position(),
+            gpui::Point::new(0., 3.)
+        );
+    });
+}
+
 #[gpui::test]
 async fn test_move_page_up_page_down(cx: &mut gpui::TestAppContext) {
     init_test(cx, |_| {});
@@ -2428,6 +2675,153 @@ async fn test_tab(cx: &mut gpui::TestAppContext) {
     "});
 }
 
+#[gpui::test]
+async fn test_drag_and_drop_selection(cx: &mut gpui::TestAppContext) {
+    init_test(cx, |_| {});
+
+    let mut cx = EditorTestContext::new(cx).await;
+    cx.set_state(indoc! {"
+        one «twoˇ» three
+        four
+    "});
+    cx.update_editor(|editor, cx| {
+        assert!(editor.begin_selection_drag(DisplayPoint::new(DisplayRow(0), 5), cx));
+        editor.update_selection_drag(DisplayPoint::new(DisplayRow(1), 2), cx);
+        editor.end_selection_drag(DisplayPoint::new(DisplayRow(1), 2), false, cx);
+    });
+    cx.assert_editor_state(indoc! {"
+        one  three
+        fo«twoˇ»ur
+    "});
+
+    cx.update_editor(|editor, cx| editor.undo(&Undo, cx));
+    cx.assert_editor_state(indoc! {"
+        one «twoˇ» three
+        four
+    "});
+
+    // Copy the text instead of moving it.
+    cx.update_editor(|editor, cx| {
+        assert!(editor.begin_selection_drag(DisplayPoint::new(DisplayRow(0), 4), cx));
+        editor.update_selection_drag(DisplayPoint::new(DisplayRow(1), 4), cx);
+        editor.end_selection_drag(DisplayPoint::new(DisplayRow(1), 4), true, cx);
+    });
+    cx.assert_editor_state(indoc! {"
+        one two three
+        four«twoˇ»
+    "});
+
+    // Releasing the mouse without moving it places a cursor.
+    cx.set_state(indoc! {"
+        one «twoˇ» three
+    "});
+    cx.update_editor(|editor, cx| {
+        assert!(editor.begin_selection_drag(DisplayPoint::new(DisplayRow(0), 5), cx));
+        editor.end_selection_drag(DisplayPoint::new(DisplayRow(0), 5), false, cx);
+    });
+    cx.assert_editor_state(indoc! {"
+        one tˇwo three
+    "});
+
+    // Drags only start within a selection.
+    cx.update_editor(|editor, cx| {
+        assert!(!editor.begin_selection_drag(DisplayPoint::new(DisplayRow(0), 1), cx));
+    });
+}
+
+#[gpui::test]
+async fn test_cancel_selection_drag(cx: &mut gpui::TestAppContext) {
+    init_test(cx, |_| {});
+
+    let mut cx = EditorTestContext::new(cx).await;
+    cx.set_state(indoc! {"
+        one «twoˇ» three
+    "});
+    cx.update_editor(|editor, cx| {
+        assert!(editor.begin_selection_drag(DisplayPoint::new(DisplayRow(0), 5), cx));
+        editor.update_selection_drag(DisplayPoint::new(DisplayRow(0), 1), cx);
+        editor.cancel(&Cancel, cx);
+        assert!(editor.selection_drag.is_none());
+        editor.end_selection_drag(DisplayPoint::new(DisplayRow(0), 1), false, cx);
+    });
+    cx.assert_editor_state(indoc! {"
+        one «twoˇ» three
+    "});
+}
+
+#[gpui::test]
+fn test_drag_selection_between_editors(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+
+    let source = cx.add_window(|cx| {
+        let buffer = MultiBuffer::build_simple("one two three", cx);
+        build_editor(buffer, cx)
+    });
+    let target = cx.add_window(|cx| {
+        let buffer = MultiBuffer::build_simple("four five", cx);
+        build_editor(buffer, cx)
+    });
+
+    _ = source.update(cx, |editor, cx| {
+        editor.change_selections(None, cx, |s| s.select_ranges([4..8]));
+        assert!(editor.begin_selection_drag(DisplayPoint::new(DisplayRow(0), 5), cx));
+        editor.hand_off_selection_drag(cx);
+        assert!(editor.selection_drag.is_none());
+    });
+    _ = target.update(cx, |editor, cx| {
+        editor.update_selection_drag(DisplayPoint::new(DisplayRow(0), 5), cx);
+        assert!(editor.selection_drag.is_some());
+
+        let dragged = cx.take_active_drag::<DraggedSelection>().unwrap();
+        editor.drop_selection(dragged, DisplayPoint::new(DisplayRow(0), 5), false, cx);
+        assert!(editor.selection_drag.is_none());
+        assert_eq!(editor.text(cx), "four two five");
+        assert_eq!(editor.selections.ranges(cx), vec![5..9]);
+    });
+    _ = source.update(cx, |editor, cx| {
+        assert_eq!(editor.text(cx), "one three");
+    });
+}
+
+#[gpui::test]
+async fn test_align_cursors(cx: &mut gpui::TestAppContext) {
+    init_test(cx, |_| {});
+
+    let mut cx = EditorTestContext::new(cx).await;
+    cx.set_state(indoc! {"
+        let aˇ = 1;
+        let abcˇ = 2;
+        let abˇ = 3;
+    "});
+    cx.update_editor(|e, cx| e.align_cursors(&AlignCursors, cx));
+    cx.assert_editor_state(indoc! {"
+        let a  ˇ = 1;
+        let abcˇ = 2;
+        let ab ˇ = 3;
+    "});
+
+    // The n-th selection of each row is aligned with the n-th selection of the other rows.
+    cx.set_state(indoc! {"
+        [ˇ1,ˇ 22,ˇ 3],
+        [ˇ444,ˇ 5,ˇ 66],
+    "});
+    cx.update_editor(|e, cx| e.align_cursors(&AlignCursors, cx));
+    cx.assert_editor_state(indoc! {"
+        [ˇ1,  ˇ 22,ˇ 3],
+        [ˇ444,ˇ 5, ˇ 66],
+    "});
+
+    cx.set_state(indoc! {"
+        x «=ˇ» 1
+        yyy «=ˇ» 2
+    "});
+    cx.update_editor(|e, cx| e.align_cursors(&AlignCursors, cx));
+    cx.assert_editor_state(indoc! {"
+        x   «=ˇ» 1
+        yyy «=ˇ» 2
+    "});
+}
+
 #[gpui::test]
 async fn test_tab_in_leading_whitespace_auto_indents_lines(cx: &mut gpui::TestAppContext) {
     init_test(cx, |_| {});
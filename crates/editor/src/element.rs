@@ -17,12 +17,12 @@ use crate::{
     hunk_status,
     items::BufferSearchHighlights,
     mouse_context_menu::{self, MouseContextMenu},
-    scroll::scroll_amount::ScrollAmount,
+    scroll::{scroll_amount::ScrollAmount, Autoscroll},
     CodeActionsMenu, CursorShape, DisplayPoint, DisplayRow, DocumentHighlightRead,
-    DocumentHighlightWrite, Editor, EditorMode, EditorSettings, EditorSnapshot, EditorStyle,
-    ExpandExcerpts, GutterDimensions, HalfPageDown, HalfPageUp, HoveredCursor, HunkToExpand,
-    LineDown, LineUp, OpenExcerpts, PageDown, PageUp, Point, RowExt, RowRangeExt, SelectPhase,
-    Selection, SoftWrap, ToPoint, CURSORS_VISIBLE_FOR, MAX_LINE_LEN,
+    DocumentHighlightWrite, DraggedSelection, Editor, EditorMode, EditorSettings, EditorSnapshot,
+    EditorStyle, ExpandExcerpts, GutterDimensions, HalfPageDown, HalfPageUp, HoveredCursor,
+    HunkToExpand, LineDown, LineUp, OpenExcerpts, PageDown, PageUp, Point, RowExt, RowRangeExt,
+    SelectPhase, Selection, SoftWrap, ToPoint, CURSORS_VISIBLE_FOR, MAX_LINE_LEN,
 };
 use client::ParticipantIndex;
 use collections::{BTreeMap, HashMap};
@@ -34,15 +34,19 @@ use gpui::{
     FontId, GlobalElementId, Hitbox, Hsla, InteractiveElement, IntoElement, Length,
     ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent, PaintQuad,
     ParentElement, Pixels, ScrollDelta, ScrollWheelEvent, ShapedLine, SharedString, Size,
-    StatefulInteractiveElement, Style, Styled, TextRun, TextStyle, TextStyleRefinement, View,
-    ViewContext, WeakView, WindowContext,
+    StatefulInteractiveElement, Style, Styled, StyledText, TextRun, TextStyle, TextStyleRefinement,
+    View, ViewContext, WeakView, WindowContext,
 };
 use itertools::Itertools;
-use language::language_settings::{
-    IndentGuideBackgroundColoring, IndentGuideColoring, IndentGuideSettings, ShowWhitespaceSetting,
+use language::{
+    language_settings::{
+        IndentGuideBackgroundColoring, IndentGuideColoring, IndentGuideSettings,
+        ShowWhitespaceSetting,
+    },
+    OutlineItem,
 };
 use lsp::DiagnosticSeverity;
-use multi_buffer::{Anchor, MultiBufferPoint, MultiBufferRow};
+use multi_buffer::{Anchor, MultiBufferPoint, MultiBufferRow, MultiBufferSnapshot};
 use project::{
     project_settings::{GitGutterSetting, ProjectSettings},
     ProjectPath,
@@ -59,7 +63,7 @@ use std::{
     sync::Arc,
 };
 use sum_tree::Bias;
-use theme::{ActiveTheme, PlayerColor};
+use theme::{ActiveTheme, PlayerColor, SyntaxTheme};
 use ui::prelude::*;
 use ui::{h_flex, ButtonLike, ButtonStyle, ContextMenu, Tooltip};
 use util::ResultExt;
@@ -267,6 +271,7 @@ impl EditorElement {
         register_action(view, cx, Editor::split_selection_into_lines);
         register_action(view, cx, Editor::add_selection_above);
         register_action(view, cx, Editor::add_selection_below);
+        register_action(view, cx, Editor::align_cursors);
         register_action(view, cx, |editor, action, cx| {
             editor.select_next(action, cx).log_err();
         });
@@ -313,8 +318,10 @@ impl EditorElement {
         });
         register_action(view, cx, Editor::open_url);
         register_action(view, cx, Editor::fold);
+        register_action(view, cx, Editor::fold_all);
         register_action(view, cx, Editor::fold_at);
         register_action(view, cx, Editor::unfold_lines);
+        register_action(view, cx, Editor::unfold_all);
         register_action(view, cx, Editor::unfold_at);
         register_action(view, cx, Editor::fold_selected_ranges);
         register_action(view, cx, Editor::show_completions);
@@ -508,6 +515,13 @@ impl EditorElement {
                 MultiCursorModifier::Alt => modifiers.alt,
                 MultiCursorModifier::CmdOrCtrl => modifiers.secondary(),
             };
+            if click_count == 1
+                && !multi_cursor_modifier
+                && editor.begin_selection_drag(position, cx)
+            {
+                cx.stop_propagation();
+                return;
+            }
             editor.select(
                 SelectPhase::Begin {
                     position,
@@ -574,6 +588,33 @@ impl EditorElement {
         text_hitbox: &Hitbox,
         cx: &mut ViewContext<Editor>,
     ) {
+        if cx.active_drag_value::<DraggedSelection>().is_some() {
+            if text_hitbox.is_hovered(cx) {
+                if let Some(dragged) = cx.take_active_drag::<DraggedSelection>() {
+                    let point_for_position =
+                        position_map.point_for_position(text_hitbox.bounds, event.position);
+                    editor.drop_selection(
+                        dragged,
+                        point_for_position.previous_valid,
+                        event.modifiers.alt,
+                        cx,
+                    );
+                    cx.stop_propagation();
+                }
+            } else {
+                editor.clear_selection_drop_preview(cx);
+            }
+            return;
+        }
+
+        if editor.selection_drag.is_some() {
+            let point_for_position =
+                position_map.point_for_position(text_hitbox.bounds, event.position);
+            editor.end_selection_drag(point_for_position.previous_valid, event.modifiers.alt, cx);
+            cx.stop_propagation();
+            return;
+        }
+
         let end_selection = editor.has_pending_selection();
         let pending_nonempty_selections = editor.has_pending_nonempty_selection();
 
@@ -626,6 +667,18 @@ impl EditorElement {
         text_bounds: Bounds<Pixels>,
         cx: &mut ViewContext<Editor>,
     ) {
+        if editor.selection_drag.is_some() {
+            // Once the mouse leaves the editor, the text can be dropped into another one.
+            if text_bounds.contains(&event.position) {
+                let point_for_position =
+                    position_map.point_for_position(text_bounds, event.position);
+                editor.update_selection_drag(point_for_position.previous_valid, cx);
+            } else {
+                editor.hand_off_selection_drag(cx);
+            }
+            return;
+        }
+
         if !editor.has_pending_selection() {
             return;
         }
@@ -1055,6 +1108,34 @@ impl EditorElement {
                     cursors.push(cursor);
                 }
             }
+
+            // Preview where dragged text will be dropped.
+            if let Some(drop_position) = editor
+                .selection_drag
+                .as_ref()
+                .and_then(|selection_drag| selection_drag.drop_position)
+            {
+                let drop_position = drop_position.to_display_point(&snapshot.display_snapshot);
+                if visible_display_row_range.contains(&drop_position.row()) {
+                    let row_layout = &line_layouts
+                        [drop_position.row().minus(visible_display_row_range.start) as usize];
+                    let x = row_layout.x_for_index(drop_position.column() as usize)
+                        - scroll_pixel_position.x;
+                    let y = (drop_position.row().as_f32() - scroll_pixel_position.y / line_height)
+                        * line_height;
+                    let mut cursor = CursorLayout::new(
+                        point(x, y),
+                        em_width,
+                        line_height,
+                        cx.theme().players().local().cursor.opacity(0.6),
+                        CursorShape::Bar,
+                        None,
+                    );
+                    cursor.layout(content_origin, None, cx);
+                    cursors.push(cursor);
+                }
+            }
+
             cursors
         });
 
@@ -1142,6 +1223,246 @@ impl EditorElement {
         })
     }
 
+    fn layout_minimap(
+        &self,
+        snapshot: &EditorSnapshot,
+        bounds: Bounds<Pixels>,
+        scroll_position: gpui::Point<f32>,
+        rows_per_page: f32,
+        cx: &mut WindowContext,
+    ) -> Option<MinimapLayout> {
+        let settings = EditorSettings::get_global(cx).minimap;
+        if !settings.show || snapshot.mode != EditorMode::Full {
+            return None;
+        }
+
+        let right = self.scrollbar_left(&bounds);
+        let minimap_bounds = Bounds::from_corners(
+            point(right - px(settings.width), bounds.top()),
+            point(right, bounds.bottom()),
+        );
+
+        // Only the rows that fit into the minimap are laid out, so the cost of
+        // each frame doesn't grow with the size of the buffer. When the buffer is
+        // taller than the minimap, the minimap scrolls along with the editor.
+        let total_rows = snapshot.max_point().row().next_row().as_f32();
+        let minimap_rows = (minimap_bounds.size.height / MinimapLayout::ROW_HEIGHT).floor();
+        let max_scroll_top = (total_rows - rows_per_page).max(0.);
+        let max_minimap_scroll_top = (total_rows - minimap_rows).max(0.);
+        let minimap_scroll_top = if max_scroll_top > 0. {
+            ((scroll_position.y / max_scroll_top).min(1.) * max_minimap_scroll_top).floor()
+        } else {
+            0.
+        };
+        let start_row = DisplayRow(minimap_scroll_top as u32);
+        let end_row =
+            DisplayRow(((minimap_scroll_top + minimap_rows) as u32).min(total_rows as u32));
+
+        let colors = cx.theme().colors();
+        let text_color = colors.editor_foreground;
+        let max_columns = (minimap_bounds.size.width / MinimapLayout::COLUMN_WIDTH) as u32;
+        let row_origin = |row: u32, column: u32| {
+            point(
+                minimap_bounds.left() + MinimapLayout::COLUMN_WIDTH * column as f32,
+                minimap_bounds.top() + MinimapLayout::ROW_HEIGHT * (row - start_row.0) as f32,
+            )
+        };
+
+        let start_offset = DisplayPoint::new(start_row, 0).to_offset(snapshot, Bias::Left);
+        let end_offset = if end_row > snapshot.max_point().row() {
+            snapshot.buffer_snapshot.len()
+        } else {
+            DisplayPoint::new(end_row, 0).to_offset(snapshot, Bias::Right)
+        };
+        let cache_key = MinimapCacheKey {
+            edit_count: snapshot.buffer_snapshot.edit_count(),
+            non_text_state_update_count: snapshot.buffer_snapshot.non_text_state_update_count(),
+            fold_version: snapshot.display_snapshot.fold_snapshot.version,
+            max_point: snapshot.max_point(),
+            rows: start_row..end_row,
+            offsets: start_offset..end_offset,
+            max_columns,
+            text_color,
+            syntax_theme: self.style.syntax.clone(),
+        };
+        let cached_runs = self
+            .editor
+            .read(cx)
+            .minimap_cache
+            .as_ref()
+            .filter(|cache| cache.key == cache_key)
+            .map(|cache| cache.runs.clone());
+        let runs = cached_runs.unwrap_or_else(|| {
+            let runs =
+                self.layout_minimap_runs(snapshot, start_row..end_row, max_columns, text_color);
+            self.editor.update(cx, |editor, _| {
+                editor.minimap_cache = Some(MinimapCache {
+                    key: cache_key,
+                    runs: runs.clone(),
+                });
+            });
+            runs
+        });
+        let quads = runs
+            .iter()
+            .map(|run| {
+                fill(
+                    Bounds {
+                        origin: row_origin(run.row, run.columns.start),
+                        size: size(
+                            MinimapLayout::COLUMN_WIDTH * run.columns.len() as f32,
+                            MinimapLayout::ROW_HEIGHT,
+                        ),
+                    },
+                    run.color,
+                )
+            })
+            .collect();
+
+        let start_anchor = snapshot.buffer_snapshot.anchor_before(start_offset);
+        let end_anchor = if end_row > snapshot.max_point().row() {
+            Anchor::max()
+        } else {
+            snapshot.buffer_snapshot.anchor_before(end_offset)
+        };
+        let mut marker_quads = Vec::new();
+        let highlights = self.editor.read(cx).background_highlights_in_range(
+            start_anchor..end_anchor,
+            &snapshot.display_snapshot,
+            colors,
+        );
+        for (range, color) in highlights {
+            let start = range.start.row().max(start_row);
+            let end = range.end.row().next_row().min(end_row);
+            if start < end {
+                marker_quads.push(fill(
+                    Bounds::from_corners(
+                        row_origin(start.0, 0),
+                        point(minimap_bounds.right(), row_origin(end.0, 0).y),
+                    ),
+                    color,
+                ));
+            }
+        }
+
+        let status = cx.theme().status();
+        let diagnostics = snapshot
+            .buffer_snapshot
+            .diagnostics_in_range::<_, Point>(start_anchor..end_anchor, false)
+            .sorted_by_key(|diagnostic| std::cmp::Reverse(diagnostic.diagnostic.severity));
+        for diagnostic in diagnostics {
+            let start = diagnostic
+                .range
+                .start
+                .to_display_point(&snapshot.display_snapshot)
+                .row()
+                .max(start_row);
+            let end = diagnostic
+                .range
+                .end
+                .to_display_point(&snapshot.display_snapshot)
+                .row()
+                .next_row()
+                .min(end_row);
+            let color = match diagnostic.diagnostic.severity {
+                DiagnosticSeverity::ERROR => status.error,
+                DiagnosticSeverity::WARNING => status.warning,
+                DiagnosticSeverity::INFORMATION => status.info,
+                _ => status.hint,
+            };
+            if start < end {
+                marker_quads.push(fill(
+                    Bounds::from_corners(
+                        point(
+                            minimap_bounds.right() - MinimapLayout::MARKER_WIDTH,
+                            row_origin(start.0, 0).y,
+                        ),
+                        point(minimap_bounds.right(), row_origin(end.0, 0).y),
+                    ),
+                    color,
+                ));
+            }
+        }
+
+        let viewport_top = minimap_bounds.top()
+            + MinimapLayout::ROW_HEIGHT * (scroll_position.y - start_row.as_f32());
+        let viewport_bounds = Bounds::from_corners(
+            point(minimap_bounds.left(), viewport_top),
+            point(
+                minimap_bounds.right(),
+                viewport_top + MinimapLayout::ROW_HEIGHT * rows_per_page,
+            ),
+        );
+
+        Some(MinimapLayout {
+            hitbox: cx.insert_hitbox(minimap_bounds, false),
+            start_row,
+            rows_per_page,
+            quads,
+            marker_quads,
+            viewport_bounds,
+        })
+    }
+
+    /// Lays out a run of minimap columns for each stretch of non-whitespace text in the
+    /// given rows, relative to the first row. Tabs advance to the next tab stop, so the
+    /// runs line up with the text in the editor.
+    fn layout_minimap_runs(
+        &self,
+        snapshot: &EditorSnapshot,
+        rows: Range<DisplayRow>,
+        max_columns: u32,
+        text_color: Hsla,
+    ) -> Arc<[MinimapRun]> {
+        let tab_size = snapshot.tab_size().get();
+        let mut runs = Vec::new();
+        let mut push_run = |row: u32, columns: Range<u32>, color: Hsla| {
+            let columns = columns.start.min(max_columns)..columns.end.min(max_columns);
+            if !columns.is_empty() {
+                runs.push(MinimapRun {
+                    row,
+                    columns,
+                    color,
+                });
+            }
+        };
+
+        let mut row = rows.start.0;
+        let mut column = 0;
+        for chunk in snapshot.highlighted_chunks(rows, true, &self.style) {
+            let color = chunk
+                .style
+                .and_then(|style| style.color)
+                .unwrap_or(text_color)
+                .opacity(0.6);
+            for (ix, line) in chunk.text.split('\n').enumerate() {
+                if ix > 0 {
+                    row += 1;
+                    column = 0;
+                }
+                let mut run_start = None;
+                for ch in line.chars() {
+                    if ch.is_whitespace() {
+                        if let Some(start) = run_start.take() {
+                            push_run(row, start..column, color);
+                        }
+                    } else if run_start.is_none() {
+                        run_start = Some(column);
+                    }
+                    column += if ch == '\t' {
+                        tab_size - column % tab_size
+                    } else {
+                        1
+                    };
+                }
+                if let Some(start) = run_start {
+                    push_run(row, start..column, color);
+                }
+            }
+        }
+        runs.into()
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn prepaint_gutter_fold_toggles(
         &self,
@@ -1373,6 +1694,106 @@ impl EditorElement {
         Some(element)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn layout_sticky_scroll(
+        &self,
+        snapshot: &EditorSnapshot,
+        start_row: DisplayRow,
+        text_hitbox: &Hitbox,
+        content_origin: gpui::Point<Pixels>,
+        line_height: Pixels,
+        em_advance: Pixels,
+        cx: &mut WindowContext,
+    ) -> Option<AnyElement> {
+        let settings = EditorSettings::get_global(cx).sticky_scroll;
+        if !settings.enabled || settings.max_lines == 0 || snapshot.mode != EditorMode::Full {
+            return None;
+        }
+
+        let buffer = &snapshot.buffer_snapshot;
+        let top_row = DisplayPoint::new(start_row, 0)
+            .to_point(&snapshot.display_snapshot)
+            .row;
+        let cache_key = StickyScrollCacheKey {
+            edit_count: buffer.edit_count(),
+            non_text_state_update_count: buffer.non_text_state_update_count(),
+            top_row,
+            max_lines: settings.max_lines,
+            syntax_theme: self.style.syntax.clone(),
+        };
+        let cached_symbols = self
+            .editor
+            .read(cx)
+            .sticky_scroll_cache
+            .as_ref()
+            .filter(|cache| cache.key == cache_key)
+            .map(|cache| cache.symbols.clone());
+        let pinned_symbols = cached_symbols.unwrap_or_else(|| {
+            let (content_row, symbols) = sticky_scroll_symbols(
+                buffer,
+                top_row,
+                settings.max_lines,
+                Some(self.style.syntax.as_ref()),
+            );
+            let symbols = Arc::<[OutlineItem<Anchor>]>::from(symbols);
+            self.editor.update(cx, |editor, _| {
+                editor.sticky_scroll_cache = Some(StickyScrollCache {
+                    key: cache_key,
+                    content_row,
+                    symbols: symbols.clone(),
+                });
+            });
+            symbols
+        });
+        if pinned_symbols.is_empty() {
+            return None;
+        }
+
+        let colors = cx.theme().colors();
+        let background = colors.editor_background;
+        let hover_background = colors.editor_active_line_background;
+        let border_color = colors.border_variant;
+        let text_offset = content_origin.x - text_hitbox.origin.x;
+
+        let mut element = v_flex()
+            .id("sticky-scroll")
+            .w(text_hitbox.size.width - Self::SCROLLBAR_WIDTH)
+            .bg(background)
+            .border_b_1()
+            .border_color(border_color)
+            .children(pinned_symbols.iter().enumerate().map(|(ix, symbol)| {
+                let start = symbol.range.start;
+                let indent = buffer
+                    .indent_size_for_line(MultiBufferRow(start.to_point(buffer).row))
+                    .len;
+                let editor = self.editor.clone();
+                div()
+                    .id(("sticky-scroll-line", ix))
+                    .h(line_height)
+                    .pl(text_offset + em_advance * indent as f32)
+                    .cursor_pointer()
+                    .hover(move |style| style.bg(hover_background))
+                    .child(
+                        StyledText::new(symbol.text.clone())
+                            .with_highlights(&self.style.text, symbol.highlight_ranges.clone()),
+                    )
+                    .on_mouse_down(MouseButton::Left, move |_, cx| {
+                        editor.update(cx, |editor, cx| {
+                            editor.change_selections(
+                                Some(Autoscroll::top_relative(ix)),
+                                cx,
+                                |selections| selections.select_anchor_ranges([start..start]),
+                            );
+                        });
+                        cx.stop_propagation();
+                    })
+            }))
+            .into_any();
+
+        element.prepaint_as_root(text_hitbox.origin, AvailableSpace::min_size(), cx);
+        Some(element)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn layout_blame_entries(
         &self,
@@ -3425,6 +3846,98 @@ impl EditorElement {
         }
     }
 
+    fn paint_minimap(&mut self, layout: &mut EditorLayout, cx: &mut WindowContext) {
+        let Some(minimap_layout) = layout.minimap_layout.take() else {
+            return;
+        };
+
+        cx.paint_layer(minimap_layout.hitbox.bounds, |cx| {
+            cx.paint_quad(fill(
+                minimap_layout.hitbox.bounds,
+                cx.theme().colors().editor_background,
+            ));
+            for quad in minimap_layout
+                .quads
+                .into_iter()
+                .chain(minimap_layout.marker_quads)
+            {
+                cx.paint_quad(quad);
+            }
+            cx.paint_quad(fill(
+                minimap_layout.viewport_bounds,
+                cx.theme().colors().scrollbar_thumb_background,
+            ));
+        });
+
+        cx.set_cursor_style(CursorStyle::Arrow, &minimap_layout.hitbox);
+
+        let hitbox = minimap_layout.hitbox;
+        let top = hitbox.top();
+        let start_row = minimap_layout.start_row;
+        let rows_per_page = minimap_layout.rows_per_page;
+        let scroll_to = move |y: Pixels, editor: &mut Editor, cx: &mut ViewContext<Editor>| {
+            let mut position = editor.scroll_position(cx);
+            position.y = MinimapLayout::scroll_top_for_position(y, top, start_row, rows_per_page);
+            editor.set_scroll_position(position, cx);
+        };
+
+        cx.on_mouse_event({
+            let editor = self.editor.clone();
+            let hitbox = hitbox.clone();
+            move |event: &MouseDownEvent, phase, cx| {
+                if phase == DispatchPhase::Capture
+                    || event.button != MouseButton::Left
+                    || !hitbox.is_hovered(cx)
+                {
+                    return;
+                }
+
+                editor.update(cx, |editor, cx| {
+                    editor.scroll_manager.set_is_dragging_minimap(true, cx);
+                    scroll_to(event.position.y, editor, cx);
+                    cx.stop_propagation();
+                });
+            }
+        });
+
+        cx.on_mouse_event({
+            let editor = self.editor.clone();
+            move |event: &MouseMoveEvent, phase, cx| {
+                if phase == DispatchPhase::Capture {
+                    return;
+                }
+
+                editor.update(cx, |editor, cx| {
+                    if !editor.scroll_manager.is_dragging_minimap() {
+                        return;
+                    }
+                    if event.pressed_button == Some(MouseButton::Left) {
+                        scroll_to(event.position.y, editor, cx);
+                        cx.stop_propagation();
+                    } else {
+                        editor.scroll_manager.set_is_dragging_minimap(false, cx);
+                    }
+                });
+            }
+        });
+
+        cx.on_mouse_event({
+            let editor = self.editor.clone();
+            move |_: &MouseUpEvent, phase, cx| {
+                if phase == DispatchPhase::Capture {
+                    return;
+                }
+
+                editor.update(cx, |editor, cx| {
+                    if editor.scroll_manager.is_dragging_minimap() {
+                        editor.scroll_manager.set_is_dragging_minimap(false, cx);
+                        cx.stop_propagation();
+                    }
+                });
+            }
+        });
+    }
+
     fn collect_fast_scrollbar_markers(
         &self,
         layout: &EditorLayout,
@@ -3677,6 +4190,14 @@ impl EditorElement {
         }
     }
 
+    fn paint_sticky_scroll(&mut self, layout: &mut EditorLayout, cx: &mut WindowContext) {
+        if let Some(mut sticky_scroll) = layout.sticky_scroll.take() {
+            cx.with_element_namespace("sticky_scroll", |cx| {
+                cx.paint_layer(layout.text_hitbox.bounds, |cx| sticky_scroll.paint(cx))
+            });
+        }
+    }
+
     fn paint_mouse_context_menu(&mut self, layout: &mut EditorLayout, cx: &mut WindowContext) {
         if let Some(mouse_context_menu) = layout.mouse_context_menu.as_mut() {
             mouse_context_menu.paint(cx);
@@ -3808,6 +4329,17 @@ impl EditorElement {
             move |event: &MouseMoveEvent, phase, cx| {
                 if phase == DispatchPhase::Bubble {
                     editor.update(cx, |editor, cx| {
+                        // Preview where text dragged out of an editor would be dropped.
+                        if cx.active_drag_value::<DraggedSelection>().is_some() {
+                            if text_hitbox.is_hovered(cx) {
+                                let point_for_position = position_map
+                                    .point_for_position(text_hitbox.bounds, event.position);
+                                editor.update_selection_drag(point_for_position.previous_valid, cx);
+                            } else {
+                                editor.clear_selection_drop_preview(cx);
+                            }
+                            return;
+                        }
                         if editor.hover_state.focused(cx) {
                             return;
                         }
@@ -3918,7 +4450,16 @@ fn render_inline_blame_entry(
     let relative_timestamp = blame_entry_relative_timestamp(&blame_entry, cx);
 
     let author = blame_entry.author.as_deref().unwrap_or_default();
-    let text = format!("{}, {}", author, relative_timestamp);
+    let show_commit_summary = ProjectSettings::get_global(cx)
+        .git
+        .inline_blame
+        .map_or(false, |settings| settings.show_commit_summary);
+    let text = match blame_entry.summary.as_deref() {
+        Some(summary) if show_commit_summary => {
+            format!("{}, {} - {}", author, relative_timestamp, summary)
+        }
+        _ => format!("{}, {}", author, relative_timestamp),
+    };
 
     let details = blame.read(cx).details_for_entry(&blame_entry);
 
@@ -4046,6 +4587,44 @@ fn deploy_blame_entry_context_menu(
     });
 }
 
+/// The symbols whose first lines are pinned to the top of the editor by sticky scroll when
+/// `top_row` is the first buffer row in the viewport, outermost first. The pinned lines cover
+/// the rows below `top_row`, so the symbols are the ones around the first row that stays visible
+/// under them, which is returned alongside.
+pub(crate) fn sticky_scroll_symbols(
+    buffer: &MultiBufferSnapshot,
+    top_row: u32,
+    max_lines: usize,
+    theme: Option<&SyntaxTheme>,
+) -> (u32, Vec<OutlineItem<Anchor>>) {
+    let pinned_count = symbols_pinned_above(buffer, top_row, max_lines, None).len() as u32;
+    if pinned_count == 0 {
+        return (top_row, Vec::new());
+    }
+    let content_row = (top_row + pinned_count).min(buffer.max_point().row);
+    (
+        content_row,
+        symbols_pinned_above(buffer, content_row, max_lines, theme),
+    )
+}
+
+/// The symbols containing `row` that start above it, outermost first.
+pub(crate) fn symbols_pinned_above(
+    buffer: &MultiBufferSnapshot,
+    row: u32,
+    max_lines: usize,
+    theme: Option<&SyntaxTheme>,
+) -> Vec<OutlineItem<Anchor>> {
+    let Some((_, symbols)) = buffer.symbols_containing(Point::new(row, 0), theme) else {
+        return Vec::new();
+    };
+    symbols
+        .into_iter()
+        .filter(|symbol| symbol.range.start.to_point(buffer).row < row)
+        .take(max_lines)
+        .collect()
+}
+
 #[derive(Debug)]
 pub(crate) struct LineWithInvisibles {
     fragments: SmallVec<[LineFragment; 1]>,
@@ -4310,6 +4889,20 @@ impl LineWithInvisibles {
             }
         }
 
+        if let Some(soft_wrap_indicator) = layout.soft_wrap_indicator.as_ref() {
+            let snapshot = &layout.position_map.snapshot;
+            if snapshot.soft_wrap_indent(row).is_some() && !snapshot.is_block_line(row) {
+                let origin = fragment_origin
+                    + gpui::point(
+                        (layout.position_map.em_width - soft_wrap_indicator.width)
+                            .max(Pixels::ZERO)
+                            / 2.,
+                        Pixels::ZERO,
+                    );
+                soft_wrap_indicator.paint(origin, line_height, cx).log_err();
+            }
+        }
+
         self.draw_invisibles(
             &selection_ranges,
             layout,
@@ -4420,9 +5013,73 @@ impl LineWithInvisibles {
                     last_seen = Some((should_render, end, paint));
                 }
             }
+            ShowWhitespaceSetting::Trailing => {
+                let trailing_start = self.trailing_whitespace_start(&layout.position_map, row);
+                if let Some(trailing_start) = trailing_start {
+                    let start_x = self.x_for_index(trailing_start);
+                    let end_x = self.x_for_index(self.len);
+                    let origin = content_origin
+                        + gpui::point(
+                            start_x - layout.position_map.scroll_pixel_position.x,
+                            line_y,
+                        );
+                    cx.paint_quad(fill(
+                        Bounds {
+                            origin,
+                            size: size(end_x - start_x, line_height),
+                        },
+                        cx.theme().status().error_background,
+                    ));
+                }
+
+                invisible_iter.for_each(|([start, _], paint)| {
+                    let invisible_point = DisplayPoint::new(row, start as u32);
+                    if trailing_start.map_or(false, |trailing_start| start >= trailing_start)
+                        || selection_ranges.iter().any(|region| {
+                            region.start <= invisible_point && invisible_point < region.end
+                        })
+                    {
+                        paint(cx);
+                    }
+                });
+            }
         };
     }
 
+    /// Returns the index where the run of whitespace at the end of this line starts. Rows ending
+    /// at a soft wrap have no trailing whitespace, since their line continues on the next row.
+    fn trailing_whitespace_start(
+        &self,
+        position_map: &PositionMap,
+        row: DisplayRow,
+    ) -> Option<usize> {
+        let mut trailing_start = self.len;
+        for invisible in self.invisibles.iter().rev() {
+            let (start, end) = match invisible {
+                Invisible::Tab {
+                    line_start_offset,
+                    line_end_offset,
+                } => (*line_start_offset, *line_end_offset),
+                Invisible::Whitespace { line_offset } => (*line_offset, line_offset + 1),
+            };
+            if end != trailing_start {
+                break;
+            }
+            trailing_start = start;
+        }
+        if trailing_start == self.len {
+            return None;
+        }
+
+        let snapshot = &position_map.snapshot;
+        let row_end = snapshot
+            .display_point_to_point(DisplayPoint::new(row, snapshot.line_len(row)), Bias::Left);
+        let line_len = snapshot
+            .buffer_snapshot
+            .line_len(MultiBufferRow(row_end.row));
+        (row_end.column == line_len).then_some(trailing_start)
+    }
+
     pub fn x_for_index(&self, index: usize) -> Pixels {
         let mut fragment_start_x = Pixels::ZERO;
         let mut fragment_start_index = 0;
@@ -4701,7 +5358,13 @@ impl Element for EditorElement {
                     let text_width = bounds.size.width - gutter_dimensions.width;
 
                     let right_margin = if snapshot.mode == EditorMode::Full {
-                        EditorElement::SCROLLBAR_WIDTH
+                        let minimap = EditorSettings::get_global(cx).minimap;
+                        let minimap_width = if minimap.show {
+                            px(minimap.width)
+                        } else {
+                            px(0.)
+                        };
+                        EditorElement::SCROLLBAR_WIDTH + minimap_width
                     } else {
                         px(0.)
                     };
@@ -5047,6 +5710,18 @@ impl Element for EditorElement {
                         );
                     });
 
+                    let sticky_scroll = cx.with_element_namespace("sticky_scroll", |cx| {
+                        self.layout_sticky_scroll(
+                            &snapshot,
+                            start_row,
+                            &text_hitbox,
+                            content_origin,
+                            line_height,
+                            em_advance,
+                            cx,
+                        )
+                    });
+
                     let cursors = self.collect_cursors(&snapshot, cx);
                     let visible_row_range = start_row..end_row;
                     let non_visible_cursors = cursors
@@ -5077,6 +5752,14 @@ impl Element for EditorElement {
                         cx,
                     );
 
+                    let minimap_layout = self.layout_minimap(
+                        &snapshot,
+                        bounds,
+                        scroll_position,
+                        height_in_lines,
+                        cx,
+                    );
+
                     let gutter_settings = EditorSettings::get_global(cx).gutter;
 
                     let mut _context_menu_visible = false;
@@ -5212,6 +5895,25 @@ impl Element for EditorElement {
                             }],
                         )
                         .unwrap();
+                    let soft_wrap_indicator = EditorSettings::get_global(cx)
+                        .show_soft_wrap_indicators
+                        .then(|| {
+                            cx.text_system()
+                                .shape_line(
+                                    "↩".into(),
+                                    invisible_symbol_font_size,
+                                    &[TextRun {
+                                        len: "↩".len(),
+                                        font: self.style.text.font(),
+                                        color: cx.theme().colors().editor_invisible,
+                                        background_color: None,
+                                        underline: None,
+                                        strikethrough: None,
+                                    }],
+                                )
+                                .log_err()
+                        })
+                        .flatten();
 
                     EditorLayout {
                         mode: snapshot.mode,
@@ -5234,6 +5936,7 @@ impl Element for EditorElement {
                         gutter_dimensions,
                         content_origin,
                         scrollbar_layout,
+                        minimap_layout,
                         active_rows,
                         highlighted_rows,
                         highlighted_ranges,
@@ -5245,6 +5948,7 @@ impl Element for EditorElement {
                         blamed_display_rows,
                         inline_blame,
                         blocks,
+                        sticky_scroll,
                         cursors,
                         visible_cursors,
                         selections,
@@ -5255,6 +5959,7 @@ impl Element for EditorElement {
                         crease_trailers,
                         tab_invisible,
                         space_invisible,
+                        soft_wrap_indicator,
                     }
                 })
             })
@@ -5333,11 +6038,14 @@ impl Element for EditorElement {
                         });
                     }
 
+                    self.paint_sticky_scroll(layout, cx);
+
                     if layout.gutter_hitbox.size.width > Pixels::ZERO {
                         self.paint_gutter_highlights(layout, cx);
                         self.paint_gutter_indicators(layout, cx);
                     }
 
+                    self.paint_minimap(layout, cx);
                     self.paint_scrollbar(layout, cx);
                     self.paint_mouse_context_menu(layout, cx);
                 });
@@ -5362,6 +6070,7 @@ pub struct EditorLayout {
     gutter_dimensions: GutterDimensions,
     content_origin: gpui::Point<Pixels>,
     scrollbar_layout: Option<ScrollbarLayout>,
+    minimap_layout: Option<MinimapLayout>,
     mode: EditorMode,
     wrap_guides: SmallVec<[(Pixels, bool); 2]>,
     indent_guides: Option<Vec<IndentGuideLayout>>,
@@ -5374,6 +6083,7 @@ pub struct EditorLayout {
     blamed_display_rows: Option<Vec<AnyElement>>,
     inline_blame: Option<AnyElement>,
     blocks: Vec<BlockLayout>,
+    sticky_scroll: Option<AnyElement>,
     highlighted_ranges: Vec<(Range<DisplayPoint>, Hsla)>,
     highlighted_gutter_ranges: Vec<(Range<DisplayPoint>, Hsla)>,
     redacted_ranges: Vec<Range<DisplayPoint>>,
@@ -5387,6 +6097,7 @@ pub struct EditorLayout {
     mouse_context_menu: Option<AnyElement>,
     tab_invisible: ShapedLine,
     space_invisible: ShapedLine,
+    soft_wrap_indicator: Option<ShapedLine>,
 }
 
 impl EditorLayout {
@@ -5401,6 +6112,105 @@ struct ColoredRange<T> {
     color: Hsla,
 }
 
+struct MinimapLayout {
+    hitbox: Hitbox,
+    start_row: DisplayRow,
+    rows_per_page: f32,
+    quads: Vec<PaintQuad>,
+    marker_quads: Vec<PaintQuad>,
+    viewport_bounds: Bounds<Pixels>,
+}
+
+impl MinimapLayout {
+    const ROW_HEIGHT: Pixels = px(2.0);
+    const COLUMN_WIDTH: Pixels = px(1.0);
+    const MARKER_WIDTH: Pixels = px(3.0);
+
+    /// The scroll position that centers the viewport on the row under the given position
+    /// in a minimap whose top edge is at `top`.
+    fn scroll_top_for_position(
+        y: Pixels,
+        top: Pixels,
+        start_row: DisplayRow,
+        rows_per_page: f32,
+    ) -> f32 {
+        let row = start_row.as_f32() + (y - top) / Self::ROW_HEIGHT;
+        (row - rows_per_page / 2.).max(0.)
+    }
+}
+
+/// A stretch of non-whitespace text in the minimap, see [`EditorElement::layout_minimap_runs`].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct MinimapRun {
+    row: u32,
+    columns: Range<u32>,
+    color: Hsla,
+}
+
+/// Identifies the text and display state a [`MinimapCache`] was laid out for.
+#[derive(Clone)]
+pub(crate) struct MinimapCacheKey {
+    edit_count: usize,
+    non_text_state_update_count: usize,
+    fold_version: usize,
+    max_point: DisplayPoint,
+    rows: Range<DisplayRow>,
+    offsets: Range<usize>,
+    max_columns: u32,
+    text_color: Hsla,
+    syntax_theme: Arc<SyntaxTheme>,
+}
+
+impl PartialEq for MinimapCacheKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.edit_count == other.edit_count
+            && self.non_text_state_update_count == other.non_text_state_update_count
+            && self.fold_version == other.fold_version
+            && self.max_point == other.max_point
+            && self.rows == other.rows
+            && self.offsets == other.offsets
+            && self.max_columns == other.max_columns
+            && self.text_color == other.text_color
+            && Arc::ptr_eq(&self.syntax_theme, &other.syntax_theme)
+    }
+}
+
+/// The minimap runs laid out in the last frame, reused while the editor's text, display
+/// state and scroll window stay the same.
+pub(crate) struct MinimapCache {
+    pub(crate) key: MinimapCacheKey,
+    pub(crate) runs: Arc<[MinimapRun]>,
+}
+
+/// Identifies the text and scroll position a [`StickyScrollCache`] was computed for.
+#[derive(Clone)]
+pub(crate) struct StickyScrollCacheKey {
+    pub(crate) edit_count: usize,
+    pub(crate) non_text_state_update_count: usize,
+    pub(crate) top_row: u32,
+    pub(crate) max_lines: usize,
+    syntax_theme: Arc<SyntaxTheme>,
+}
+
+impl PartialEq for StickyScrollCacheKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.edit_count == other.edit_count
+            && self.non_text_state_update_count == other.non_text_state_update_count
+            && self.top_row == other.top_row
+            && self.max_lines == other.max_lines
+            && Arc::ptr_eq(&self.syntax_theme, &other.syntax_theme)
+    }
+}
+
+/// The symbols pinned by sticky scroll in the last frame, reused by later frames and by
+/// autoscroll while the editor's text and scroll position stay the same.
+pub(crate) struct StickyScrollCache {
+    pub(crate) key: StickyScrollCacheKey,
+    /// The first buffer row visible below the pinned lines.
+    pub(crate) content_row: u32,
+    pub(crate) symbols: Arc<[OutlineItem<Anchor>]>,
+}
+
 #[derive(Clone)]
 struct ScrollbarLayout {
     hitbox: Hitbox,
@@ -5971,6 +6781,60 @@ mod tests {
         assert_eq!(relative_rows[&DisplayRow(2)], 3);
     }
 
+    #[gpui::test]
+    fn test_minimap_runs(cx: &mut TestAppContext) {
+        init_test(cx, |_| {});
+        let window = cx.add_window(|cx| {
+            let buffer = MultiBuffer::build_simple("\tfoo bar\nx\ty\n\n  longer_word", cx);
+            Editor::new(EditorMode::Full, buffer, None, true, cx)
+        });
+
+        let editor = window.root(cx).unwrap();
+        let style = cx.update(|cx| editor.read(cx).style().unwrap().clone());
+        let element = EditorElement::new(&editor, style);
+        let snapshot = window.update(cx, |editor, cx| editor.snapshot(cx)).unwrap();
+
+        let runs = cx
+            .update_window(*window, |_, _| {
+                element.layout_minimap_runs(
+                    &snapshot,
+                    DisplayRow(0)..DisplayRow(4),
+                    8,
+                    gpui::black(),
+                )
+            })
+            .unwrap();
+        assert_eq!(
+            runs.iter()
+                .map(|run| (run.row, run.columns.clone()))
+                .collect::<Vec<_>>(),
+            [(0, 4..7), (1, 0..1), (1, 4..5), (3, 2..8)]
+        );
+    }
+
+    #[test]
+    fn test_minimap_scroll_top_for_position() {
+        let top = px(100.);
+        let start_row = DisplayRow(10);
+        let rows_per_page = 20.;
+
+        // Clicking the first minimap row centers the viewport on it.
+        assert_eq!(
+            MinimapLayout::scroll_top_for_position(top, top, start_row, rows_per_page),
+            0.
+        );
+        // Each row is two pixels tall in the minimap.
+        assert_eq!(
+            MinimapLayout::scroll_top_for_position(top + px(60.), top, start_row, rows_per_page),
+            30.
+        );
+        // Dragging above the minimap never scrolls past the top.
+        assert_eq!(
+            MinimapLayout::scroll_top_for_position(px(0.), top, start_row, rows_per_page),
+            0.
+        );
+    }
+
     #[gpui::test]
     async fn test_vim_visual_selections(cx: &mut TestAppContext) {
         init_test(cx, |_| {});
@@ -6324,6 +7188,61 @@ mod tests {
         }
     }
 
+    #[gpui::test]
+    fn test_trailing_whitespace(cx: &mut TestAppContext) {
+        init_test(cx, |s| {
+            s.defaults.show_whitespaces = Some(ShowWhitespaceSetting::Trailing);
+            s.defaults.tab_size = NonZeroU32::new(4);
+        });
+
+        // Trailing spaces and tabs start after the last visible character, interior whitespace
+        // isn't trailing, and a line of only whitespace is trailing from its start.
+        let trailing_starts =
+            trailing_whitespace_starts_from_new_editor(cx, "a  \na\t\t\na b\n  \t", px(500.0));
+        assert_eq!(trailing_starts, [Some(1), Some(1), None, Some(0)]);
+
+        // Rows ending at a soft wrap continue on the next row, so only the last one has
+        // trailing whitespace.
+        let trailing_starts =
+            trailing_whitespace_starts_from_new_editor(cx, &"aaaa bbbb ".repeat(8), px(100.0));
+        assert!(trailing_starts.len() > 1);
+        let (last, wrapped) = trailing_starts.split_last().unwrap();
+        assert!(wrapped.iter().all(Option::is_none), "{trailing_starts:?}");
+        assert!(last.is_some(), "{trailing_starts:?}");
+    }
+
+    fn trailing_whitespace_starts_from_new_editor(
+        cx: &mut TestAppContext,
+        input_text: &str,
+        editor_width: Pixels,
+    ) -> Vec<Option<usize>> {
+        let window = cx.add_window(|cx| {
+            let buffer = MultiBuffer::build_simple(input_text, cx);
+            Editor::new(EditorMode::Full, buffer, None, true, cx)
+        });
+        let cx = &mut VisualTestContext::from_window(*window, cx);
+        let editor = window.root(cx).unwrap();
+        let style = cx.update(|cx| editor.read(cx).style().unwrap().clone());
+        window
+            .update(cx, |editor, cx| {
+                editor.set_soft_wrap_mode(language_settings::SoftWrap::EditorWidth, cx);
+                editor.set_wrap_width(Some(editor_width), cx);
+            })
+            .unwrap();
+        let (_, state) = cx.draw(point(px(500.), px(500.)), size(px(500.), px(500.)), |_| {
+            EditorElement::new(&editor, style)
+        });
+        state
+            .position_map
+            .line_layouts
+            .iter()
+            .enumerate()
+            .map(|(row, line)| {
+                line.trailing_whitespace_start(&state.position_map, DisplayRow(row as u32))
+            })
+            .collect()
+    }
+
     fn collect_invisibles_from_new_editor(
         cx: &mut TestAppContext,
         editor_mode: EditorMode,
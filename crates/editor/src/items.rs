@@ -965,6 +965,7 @@ impl Item for Editor {
                         cx.new_view(|cx| {
                             let mut editor = Editor::for_buffer(buffer, Some(project), cx);
 
+                            editor.read_folds_from_db(item_id, workspace_id, cx);
                             editor.read_scroll_position_from_db(item_id, workspace_id, cx);
                             editor
                         })
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use anyhow::Result;
 use db::sqlez_macros::sql;
 use db::{define_connection, query};
 
@@ -15,6 +16,14 @@ define_connection!(
     //   scroll_vertical_offset: f32,
     //   scroll_horizontal_offset: f32,
     // )
+    // editor_folds(
+    //   item_id: usize,
+    //   workspace_id: usize,
+    //   start_offset: usize,
+    //   end_offset: usize,
+    //   start_fingerprint: Option<String>,
+    //   end_fingerprint: Option<String>,
+    // )
     pub static ref DB: EditorDb<WorkspaceDb> =
         &[sql! (
             CREATE TABLE editors(
@@ -31,6 +40,21 @@ define_connection!(
             ALTER TABLE editors ADD COLUMN scroll_top_row INTEGER NOT NULL DEFAULT 0;
             ALTER TABLE editors ADD COLUMN scroll_horizontal_offset REAL NOT NULL DEFAULT 0;
             ALTER TABLE editors ADD COLUMN scroll_vertical_offset REAL NOT NULL DEFAULT 0;
+        ),
+        sql! (
+            CREATE TABLE editor_folds(
+                item_id INTEGER NOT NULL,
+                workspace_id INTEGER NOT NULL,
+                start_offset INTEGER NOT NULL,
+                end_offset INTEGER NOT NULL,
+                FOREIGN KEY(item_id, workspace_id) REFERENCES editors(item_id, workspace_id)
+                ON DELETE CASCADE
+                ON UPDATE CASCADE
+            ) STRICT;
+        ),
+        sql! (
+            ALTER TABLE editor_folds ADD COLUMN start_fingerprint TEXT;
+            ALTER TABLE editor_folds ADD COLUMN end_fingerprint TEXT;
         )];
 );
 
@@ -80,4 +104,41 @@ impl EditorDb {
             WHERE item_id = ?1 AND workspace_id = ?2
         }
     }
+
+    query! {
+        pub fn get_folds(item_id: ItemId, workspace_id: WorkspaceId) -> Result<Vec<(usize, usize, Option<String>, Option<String>)>> {
+            SELECT start_offset, end_offset, start_fingerprint, end_fingerprint
+            FROM editor_folds
+            WHERE item_id = ? AND workspace_id = ?
+            ORDER BY start_offset
+        }
+    }
+
+    /// Replaces the folds stored for the given editor. Each fold is stored as its start and end
+    /// offsets along with fingerprints of the text at either end, which are used to discard
+    /// folds whose text has changed by the time they are restored.
+    pub async fn save_folds(
+        &self,
+        item_id: ItemId,
+        workspace_id: WorkspaceId,
+        folds: Vec<(usize, usize, String, String)>,
+    ) -> Result<()> {
+        self.write(move |conn| {
+            conn.with_savepoint("update_editor_folds", || {
+                conn.exec_bound(sql!(
+                    DELETE FROM editor_folds WHERE item_id = ?1 AND workspace_id = ?2
+                ))?((item_id, workspace_id))?;
+                for (start, end, start_fingerprint, end_fingerprint) in folds {
+                    conn.exec_bound(sql!(
+                        INSERT INTO editor_folds
+                            (item_id, workspace_id, start_offset, end_offset, start_fingerprint, end_fingerprint)
+                        VALUES
+                            (?1, ?2, ?3, ?4, ?5, ?6)
+                    ))?((item_id, workspace_id, start, end, start_fingerprint, end_fingerprint))?;
+                }
+                Ok(())
+            })
+        })
+        .await
+    }
 }
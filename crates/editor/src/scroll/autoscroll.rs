@@ -1,8 +1,11 @@
 use crate::{
-    display_map::ToDisplayPoint, DisplayRow, Editor, EditorMode, LineWithInvisibles, RowExt,
+    display_map::{DisplaySnapshot, ToDisplayPoint},
+    element::symbols_pinned_above,
+    DisplayPoint, DisplayRow, Editor, EditorMode, EditorSettings, LineWithInvisibles, RowExt,
 };
-use gpui::{px, Bounds, Pixels, ViewContext};
+use gpui::{px, AppContext, Bounds, Pixels, ViewContext};
 use language::Point;
+use settings::Settings;
 use std::{cmp, f32};
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -144,6 +147,7 @@ impl Editor {
         } else {
             ((visible_lines - (target_bottom - target_top)) / 2.0).floor()
         };
+        let sticky_scroll_rows = self.sticky_scroll_rows(&display_map, target_top, cx);
 
         let strategy = match autoscroll {
             Autoscroll::Strategy(strategy) => strategy,
@@ -167,7 +171,7 @@ impl Editor {
         match strategy {
             AutoscrollStrategy::Fit | AutoscrollStrategy::Newest => {
                 let margin = margin.min(self.scroll_manager.vertical_scroll_margin);
-                let target_top = (target_top - margin).max(0.0);
+                let target_top = (target_top - margin - sticky_scroll_rows).max(0.0);
                 let target_bottom = target_bottom + margin;
                 let start_row = scroll_position.y;
                 let end_row = start_row + visible_lines;
@@ -190,7 +194,8 @@ impl Editor {
             }
             AutoscrollStrategy::Focused => {
                 scroll_position.y =
-                    (target_top - self.scroll_manager.vertical_scroll_margin).max(0.0);
+                    (target_top - self.scroll_manager.vertical_scroll_margin - sticky_scroll_rows)
+                        .max(0.0);
                 self.set_scroll_position_internal(scroll_position, local, true, cx);
             }
             AutoscrollStrategy::Top => {
@@ -217,6 +222,30 @@ impl Editor {
         true
     }
 
+    /// The number of rows sticky scroll pins over the top of the viewport when `row` is
+    /// scrolled into view, so that autoscrolling doesn't leave the target under them.
+    fn sticky_scroll_rows(&self, display_map: &DisplaySnapshot, row: f32, cx: &AppContext) -> f32 {
+        let settings = EditorSettings::get_global(cx).sticky_scroll;
+        if !settings.enabled || self.mode != EditorMode::Full {
+            return 0.;
+        }
+        let buffer = &display_map.buffer_snapshot;
+        let buffer_row = DisplayPoint::new(DisplayRow(row as u32), 0)
+            .to_point(display_map)
+            .row;
+
+        // Reuse the symbols pinned in the last frame when they were found for the same row.
+        if let Some(cache) = self.sticky_scroll_cache.as_ref().filter(|cache| {
+            cache.key.edit_count == buffer.edit_count()
+                && cache.key.non_text_state_update_count == buffer.non_text_state_update_count()
+                && cache.key.max_lines == settings.max_lines
+                && cache.content_row == buffer_row
+        }) {
+            return cache.symbols.len() as f32;
+        }
+        symbols_pinned_above(buffer, buffer_row, settings.max_lines, None).len() as f32
+    }
+
     pub(crate) fn autoscroll_horizontally(
         &mut self,
         start_row: DisplayRow,
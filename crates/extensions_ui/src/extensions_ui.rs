@@ -757,6 +757,7 @@ impl ExtensionsPage {
             underline: None,
             strikethrough: None,
             white_space: WhiteSpace::Normal,
+            ..Default::default()
         };
 
         EditorElement::new(
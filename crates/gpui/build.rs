@@ -92,6 +92,9 @@ mod macos {
             "Pixels".into(),
             "PointF".into(),
             "Hsla".into(),
+            "ColorStop".into(),
+            "BackgroundTag".into(),
+            "Background".into(),
             "ContentMask".into(),
             "Uniforms".into(),
             "AtlasTile".into(),
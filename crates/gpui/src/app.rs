@@ -1239,6 +1239,40 @@ impl AppContext {
         self.active_drag.is_some()
     }
 
+    /// Start dragging the given value, rendering `view` under the mouse, as if an element with
+    /// an [`on_drag`](crate::StatefulInteractiveElement::on_drag) listener had been dragged. This
+    /// lets elements that handle raw mouse events hand a drag over to other elements' drop
+    /// listeners. `cursor_offset` is the position of the mouse within `view`.
+    pub fn start_drag<T: 'static>(
+        &mut self,
+        value: T,
+        view: AnyView,
+        cursor_offset: Point<Pixels>,
+    ) {
+        self.active_drag = Some(AnyDrag {
+            view,
+            value: Box::new(value),
+            cursor_offset,
+        });
+    }
+
+    /// Returns the value being dragged, if something of the given type is being dragged.
+    pub fn active_drag_value<T: 'static>(&self) -> Option<&T> {
+        self.active_drag
+            .as_ref()
+            .and_then(|drag| drag.value.downcast_ref::<T>())
+    }
+
+    /// Ends the active drag and returns its value, if something of the given type is being
+    /// dragged. Otherwise the active drag is left alone.
+    pub fn take_active_drag<T: 'static>(&mut self) -> Option<T> {
+        if self.active_drag_value::<T>().is_none() {
+            return None;
+        }
+        let drag = self.active_drag.take()?;
+        drag.value.downcast::<T>().ok().map(|value| *value)
+    }
+
     /// Set the prompt renderer for GPUI. This will replace the default or platform specific
     /// prompts with this custom implementation.
     pub fn set_prompt_builder(
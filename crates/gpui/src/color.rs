@@ -514,6 +514,149 @@ impl<'de> Deserialize<'de> for Hsla {
     }
 }
 
+/// A color at a given position along a gradient.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct ColorStop {
+    /// The color at this stop.
+    pub color: Hsla,
+    /// The position of this stop along the gradient, in the range 0.0 to 1.0
+    pub percentage: f32,
+}
+
+impl Eq for ColorStop {}
+
+/// Construct a [`ColorStop`] from a color and a position along the gradient.
+pub fn color_stop(color: impl Into<Hsla>, percentage: f32) -> ColorStop {
+    ColorStop {
+        color: color.into(),
+        percentage: percentage.clamp(0., 1.),
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub(crate) enum BackgroundTag {
+    #[default]
+    Solid = 0,
+    LinearGradient = 1,
+    RadialGradient = 2,
+}
+
+/// The maximum number of color stops in a gradient [`Background`].
+pub const MAX_COLOR_STOPS: usize = 4;
+
+/// The background of a quad: either a solid color or a gradient with up to
+/// [`MAX_COLOR_STOPS`] color stops.
+#[derive(Default, Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Background {
+    pub(crate) tag: BackgroundTag,
+    /// The direction of a linear gradient, in degrees clockwise from "to top".
+    pub(crate) angle: f32,
+    /// The center of a radial gradient, relative to the quad's bounds.
+    pub(crate) center_x: f32,
+    pub(crate) center_y: f32,
+    /// The number of entries in `colors` that are used by the gradient.
+    pub(crate) stop_count: u32,
+    pub(crate) solid: Hsla,
+    // Keep the length in sync with `MAX_COLOR_STOPS` and the shaders.
+    pub(crate) colors: [ColorStop; 4],
+    pad: u32, // align quads to 8 bytes
+}
+
+impl Eq for Background {}
+
+impl Background {
+    fn gradient(tag: BackgroundTag, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        let mut background = Background {
+            tag,
+            ..Default::default()
+        };
+        for stop in stops {
+            let ix = background.stop_count as usize;
+            debug_assert!(
+                ix < MAX_COLOR_STOPS,
+                "gradients support at most {MAX_COLOR_STOPS} color stops"
+            );
+            if ix == MAX_COLOR_STOPS {
+                break;
+            }
+            background.colors[ix] = stop;
+            background.stop_count += 1;
+        }
+        background
+    }
+
+    /// The color stops of this background, if it is a gradient.
+    pub fn color_stops(&self) -> &[ColorStop] {
+        &self.colors[..self.stop_count as usize]
+    }
+}
+
+/// Construct a linear gradient running in the direction of the given angle
+/// (in degrees, where 0 points up and 90 points right), as in CSS. The stops
+/// should be sorted by their position and there can be at most [`MAX_COLOR_STOPS`].
+pub fn linear_gradient(angle: f32, stops: impl IntoIterator<Item = ColorStop>) -> Background {
+    Background {
+        angle,
+        ..Background::gradient(BackgroundTag::LinearGradient, stops)
+    }
+}
+
+/// Construct a radial gradient around the given center, expressed relative to
+/// the painted bounds (so `point(0.5, 0.5)` is the middle). The gradient reaches
+/// its last stop at the corners of the bounds. The stops should be sorted by their
+/// position and there can be at most [`MAX_COLOR_STOPS`].
+pub fn radial_gradient(
+    center: crate::Point<f32>,
+    stops: impl IntoIterator<Item = ColorStop>,
+) -> Background {
+    Background {
+        center_x: center.x,
+        center_y: center.y,
+        ..Background::gradient(BackgroundTag::RadialGradient, stops)
+    }
+}
+
+impl Background {
+    /// Returns true if nothing would be visible when painting this background.
+    pub fn is_transparent(&self) -> bool {
+        match self.tag {
+            BackgroundTag::Solid => self.solid.is_transparent(),
+            BackgroundTag::LinearGradient | BackgroundTag::RadialGradient => self
+                .color_stops()
+                .iter()
+                .all(|stop| stop.color.is_transparent()),
+        }
+    }
+
+    /// Returns a copy of this background with every color's alpha multiplied by the given factor.
+    pub fn opacity(&self, factor: f32) -> Self {
+        let mut background = *self;
+        background.solid.a *= factor;
+        for stop in &mut background.colors {
+            stop.color.a *= factor;
+        }
+        background
+    }
+}
+
+impl From<Hsla> for Background {
+    fn from(color: Hsla) -> Self {
+        Background {
+            solid: color,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Rgba> for Background {
+    fn from(color: Rgba) -> Self {
+        Hsla::from(color).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -561,4 +704,23 @@ mod tests {
 
         assert_eq!(actual, rgba(0xdeadbeef))
     }
+
+    #[test]
+    fn test_background_opacity() {
+        let gradient = linear_gradient(
+            90.,
+            [
+                color_stop(rgba(0xff000080), 0.),
+                color_stop(rgba(0x00ff0000), 0.5),
+                color_stop(rgba(0x0000ffff), 1.),
+            ],
+        );
+        assert_eq!(gradient.color_stops().len(), 3);
+        assert!(!gradient.is_transparent());
+        assert!(gradient.opacity(0.).is_transparent());
+        assert_eq!(gradient.opacity(0.5).color_stops()[2].color.a, 0.5);
+
+        let solid = Background::from(transparent_black());
+        assert!(solid.is_transparent());
+    }
 }
@@ -19,6 +19,7 @@ pub struct Anchored {
     fit_mode: AnchoredFitMode,
     anchor_position: Option<Point<Pixels>>,
     position_mode: AnchoredPositionMode,
+    offset: Point<Pixels>,
 }
 
 /// anchored gives you an element that will avoid overflowing the window bounds.
@@ -30,6 +31,7 @@ pub fn anchored() -> Anchored {
         fit_mode: AnchoredFitMode::SwitchAnchor,
         anchor_position: None,
         position_mode: AnchoredPositionMode::Window,
+        offset: Point::default(),
     }
 }
 
@@ -55,6 +57,14 @@ impl Anchored {
         self
     }
 
+    /// Offset the anchored element from its anchor position, e.g. to leave a gap between a
+    /// popover and the element that opened it. When the anchor corner is switched to avoid
+    /// overflowing the window, the offset is mirrored along the switched axis.
+    pub fn offset(mut self, offset: Point<Pixels>) -> Self {
+        self.offset = offset;
+        self
+    }
+
     /// Snap to window edge instead of switching anchor corner when an overflow would occur.
     pub fn snap_to_window(mut self) -> Self {
         self.fit_mode = AnchoredFitMode::SnapToWindow;
@@ -124,6 +134,8 @@ impl Element for Anchored {
             size,
             bounds,
         );
+        let mut offset = self.offset;
+        desired.origin += offset;
 
         let limits = Bounds {
             origin: Point::default(),
@@ -134,19 +146,24 @@ impl Element for Anchored {
             let mut anchor_corner = self.anchor_corner;
 
             if desired.left() < limits.left() || desired.right() > limits.right() {
-                let switched = anchor_corner
+                let switched_offset = point(-offset.x, offset.y);
+                let mut switched = anchor_corner
                     .switch_axis(Axis::Horizontal)
                     .get_bounds(origin, size);
+                switched.origin += switched_offset;
                 if !(switched.left() < limits.left() || switched.right() > limits.right()) {
                     anchor_corner = anchor_corner.switch_axis(Axis::Horizontal);
+                    offset = switched_offset;
                     desired = switched
                 }
             }
 
             if desired.top() < limits.top() || desired.bottom() > limits.bottom() {
-                let switched = anchor_corner
+                let switched_offset = point(offset.x, -offset.y);
+                let mut switched = anchor_corner
                     .switch_axis(Axis::Vertical)
                     .get_bounds(origin, size);
+                switched.origin += switched_offset;
                 if !(switched.top() < limits.top() || switched.bottom() > limits.bottom()) {
                     desired = switched;
                 }
@@ -310,3 +327,68 @@ impl AnchorCorner {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        self as gpui, anchored, div, point, px, size, Bounds, InteractiveElement, IntoElement,
+        ParentElement, Pixels, Point, Render, Styled, TestAppContext,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    struct AnchoredView {
+        position: Point<Pixels>,
+        bounds: Rc<Cell<Option<Bounds<Pixels>>>>,
+    }
+
+    impl Render for AnchoredView {
+        fn render(&mut self, _: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            let bounds = self.bounds.clone();
+            div().size_full().child(
+                anchored()
+                    .position(self.position)
+                    .offset(point(px(4.), px(8.)))
+                    .child(
+                        div()
+                            .w(px(50.))
+                            .h(px(20.))
+                            .on_layout(move |element_bounds, _| bounds.set(Some(element_bounds))),
+                    ),
+            )
+        }
+    }
+
+    fn anchored_origin(position: Point<Pixels>, cx: &mut TestAppContext) -> Point<Pixels> {
+        let bounds = Rc::new(Cell::new(None));
+        cx.add_window_view(|_| AnchoredView {
+            position,
+            bounds: bounds.clone(),
+        });
+        let bounds = bounds.get().unwrap();
+        assert_eq!(bounds.size, size(px(50.), px(20.)));
+        bounds.origin
+    }
+
+    #[gpui::test]
+    fn test_offset_mirrors_when_anchor_switches(cx: &mut TestAppContext) {
+        // The test window is 1920x1080.
+        assert_eq!(
+            anchored_origin(point(px(100.), px(100.)), cx),
+            point(px(104.), px(108.))
+        );
+
+        // Overflowing the right edge anchors the element by its right edge instead, and moves it
+        // away from the anchor to the left.
+        assert_eq!(
+            anchored_origin(point(px(1900.), px(100.)), cx),
+            point(px(1846.), px(108.))
+        );
+
+        // Overflowing the bottom edge anchors the element by its bottom edge instead, and moves
+        // it away from the anchor upwards.
+        assert_eq!(
+            anchored_origin(point(px(100.), px(1070.)), cx),
+            point(px(104.), px(1042.))
+        );
+    }
+}
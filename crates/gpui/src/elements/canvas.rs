@@ -7,6 +7,9 @@ use crate::{
 
 /// Construct a canvas element with the given paint callback.
 /// Useful for adding short term custom drawing to a view.
+///
+/// The paint callback is invoked with the canvas's text style applied, so painted content
+/// can use the cascaded text color, and is clipped to its bounds if its overflow is hidden.
 pub fn canvas<T>(
     prepaint: impl 'static + FnOnce(Bounds<Pixels>, &mut WindowContext) -> T,
     paint: impl 'static + FnOnce(Bounds<Pixels>, T, &mut WindowContext),
@@ -73,7 +76,11 @@ impl<T: 'static> Element for Canvas<T> {
     ) {
         let prepaint = prepaint.take().unwrap();
         style.paint(bounds, cx, |cx| {
-            (self.paint.take().unwrap())(bounds, prepaint, cx)
+            cx.with_text_style(style.text_style().cloned(), |cx| {
+                cx.with_content_mask(style.overflow_mask(bounds, cx.rem_size()), |cx| {
+                    (self.paint.take().unwrap())(bounds, prepaint, cx)
+                })
+            })
         });
     }
 }
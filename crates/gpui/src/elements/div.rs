@@ -16,19 +16,19 @@
 //! constructed by combining these two systems into an all-in-one element.
 
 use crate::{
-    point, px, size, Action, AnyDrag, AnyElement, AnyTooltip, AnyView, AppContext, Bounds,
-    ClickEvent, DispatchPhase, Element, ElementId, FocusHandle, Global, GlobalElementId, Hitbox,
-    HitboxId, IntoElement, IsZero, KeyContext, KeyDownEvent, KeyUpEvent, LayoutId,
-    ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
-    ParentElement, Pixels, Point, Render, ScrollWheelEvent, SharedString, Size, Style,
-    StyleRefinement, Styled, Task, TooltipId, View, Visibility, WindowContext,
+    fill, hsla, point, px, size, Action, Along, AnyDrag, AnyElement, AnyTooltip, AnyView,
+    AppContext, Axis, Bounds, ClickEvent, DispatchPhase, Element, ElementId, FocusHandle, Global,
+    GlobalElementId, Hitbox, HitboxId, Hsla, IntoElement, IsZero, KeyContext, KeyDownEvent,
+    KeyUpEvent, LayoutId, ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseMoveEvent,
+    MouseUpEvent, ParentElement, PinchEvent, Pixels, Point, Render, ScrollWheelEvent, SharedString,
+    Size, Style, StyleRefinement, Styled, Task, TooltipId, View, Visibility, WindowContext,
 };
 use collections::HashMap;
 use refineable::Refineable;
 use smallvec::SmallVec;
 use std::{
     any::{Any, TypeId},
-    cell::RefCell,
+    cell::{Cell, RefCell},
     cmp::Ordering,
     fmt::Debug,
     marker::PhantomData,
@@ -277,6 +277,29 @@ impl Interactivity {
             }));
     }
 
+    /// Bind the given callback to trackpad pinch gestures over this element during the bubble phase.
+    /// Pinch gestures are only reported on macOS, see [`PinchEvent`].
+    /// The imperative API equivalent to [`InteractiveElement::on_pinch`]
+    ///
+    /// See [`ViewContext::listener`](crate::ViewContext::listener) to get access to a view's state from this callback.
+    pub fn on_pinch(&mut self, listener: impl Fn(&PinchEvent, &mut WindowContext) + 'static) {
+        self.pinch_listeners
+            .push(Box::new(move |event, phase, hitbox, cx| {
+                if phase == DispatchPhase::Bubble && hitbox.is_hovered(cx) {
+                    (listener)(event, cx);
+                }
+            }));
+    }
+
+    /// Bind the given callback to be called with this element's bounds once its layout is final,
+    /// during prepaint and before the element or its children are painted.
+    /// The imperative API equivalent to [`InteractiveElement::on_layout`]
+    ///
+    /// See [`ViewContext::listener`](crate::ViewContext::listener) to get access to a view's state from this callback.
+    pub fn on_layout(&mut self, listener: impl Fn(Bounds<Pixels>, &mut WindowContext) + 'static) {
+        self.layout_listeners.push(Box::new(listener));
+    }
+
     /// Bind the given callback to an action dispatch during the capture phase
     /// The imperative API equivalent to [`InteractiveElement::capture_action`]
     ///
@@ -434,6 +457,22 @@ impl Interactivity {
             .push(Box::new(move |event, cx| listener(event, cx)));
     }
 
+    /// Bind the given callback to be called when the primary mouse button is held down over this
+    /// element for at least `duration` without the mouse moving away. A click is not reported for
+    /// a press that triggered this callback.
+    /// The imperative API equivalent to [`StatefulInteractiveElement::on_long_press`]
+    ///
+    /// See [`ViewContext::listener`](crate::ViewContext::listener) to get access to a view's state from this callback.
+    pub fn on_long_press(
+        &mut self,
+        duration: Duration,
+        listener: impl Fn(&MouseDownEvent, &mut WindowContext) + 'static,
+    ) where
+        Self: Sized,
+    {
+        self.long_press_listener = Some((duration, Rc::new(listener)));
+    }
+
     /// On drag initiation, this callback will be used to create a new view to render the dragged value for a
     /// drag and drop operation. This API should also be used as the equivalent of 'on drag start' with
     /// the [`Self::on_drag_move`] API
@@ -517,6 +556,16 @@ impl Interactivity {
     }
 }
 
+/// A trait for elements that can be disabled. Generally used to implement disabling an element's
+/// interactivity and changing its appearance to reflect that it is disabled.
+///
+/// A disabled [`Div`] doesn't register its mouse, keyboard and tooltip listeners, and is drawn
+/// with its disabled style in place of its hover and active styles.
+pub trait Disableable {
+    /// Sets whether the element is disabled.
+    fn disabled(self, disabled: bool) -> Self;
+}
+
 /// A trait for elements that want to use the standard GPUI event handlers that don't
 /// require any state.
 pub trait InteractiveElement: Sized {
@@ -545,6 +594,14 @@ pub trait InteractiveElement: Sized {
         Focusable { element: self }
     }
 
+    /// Track the hover and active state of this element with the given handle, so that other
+    /// elements can react to it when they are rendered. The view containing this element is
+    /// notified whenever the state changes.
+    fn track_interaction(mut self, state: &InteractionState) -> Self {
+        self.interactivity().tracked_interaction = Some(state.clone());
+        self
+    }
+
     /// Set the keymap context for this element. This will be used to determine
     /// which action to dispatch from the keymap.
     fn key_context<C, E>(mut self, key_context: C) -> Self
@@ -568,6 +625,12 @@ pub trait InteractiveElement: Sized {
         self
     }
 
+    /// Apply the given style to this element while it is disabled
+    fn disabled_style(mut self, f: impl FnOnce(StyleRefinement) -> StyleRefinement) -> Self {
+        self.interactivity().disabled_style = Some(Box::new(f(StyleRefinement::default())));
+        self
+    }
+
     /// Apply the given style to this element when the mouse hovers over a group member
     fn group_hover(
         mut self,
@@ -727,6 +790,29 @@ pub trait InteractiveElement: Sized {
         self
     }
 
+    /// Bind the given callback to trackpad pinch gestures over this element during the bubble phase.
+    /// Pinch gestures are only reported on macOS, see [`PinchEvent`].
+    /// The fluent API equivalent to [`Interactivity::on_pinch`]
+    ///
+    /// See [`ViewContext::listener`](crate::ViewContext::listener) to get access to a view's state from this callback.
+    fn on_pinch(mut self, listener: impl Fn(&PinchEvent, &mut WindowContext) + 'static) -> Self {
+        self.interactivity().on_pinch(listener);
+        self
+    }
+
+    /// Bind the given callback to be called with this element's bounds once its layout is final,
+    /// during prepaint and before the element or its children are painted.
+    /// The fluent API equivalent to [`Interactivity::on_layout`]
+    ///
+    /// See [`ViewContext::listener`](crate::ViewContext::listener) to get access to a view's state from this callback.
+    fn on_layout(
+        mut self,
+        listener: impl Fn(Bounds<Pixels>, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.interactivity().on_layout(listener);
+        self
+    }
+
     /// Capture the given action, before normal action dispatch can fire
     /// The fluent API equivalent to [`Interactivity::on_scroll_wheel`]
     ///
@@ -909,6 +995,19 @@ pub trait StatefulInteractiveElement: InteractiveElement {
         self
     }
 
+    /// Paint a scrollbar along each scrolling axis whose content overflows this element.
+    /// Scrollbars are shown while the element is hovered or a thumb is being dragged,
+    /// and dragging a thumb scrolls the element.
+    fn scrollbar(self) -> Self {
+        self.scrollbar_style(ScrollbarStyle::default())
+    }
+
+    /// Paint scrollbars as with [`Self::scrollbar`], with the given size and colors.
+    fn scrollbar_style(mut self, style: ScrollbarStyle) -> Self {
+        self.interactivity().scrollbar_style = Some(style);
+        self
+    }
+
     /// Track the scroll state of this element with the given handle.
     fn track_scroll(mut self, scroll_handle: &ScrollHandle) -> Self {
         self.interactivity().tracked_scroll_handle = Some(scroll_handle.clone());
@@ -952,6 +1051,31 @@ pub trait StatefulInteractiveElement: InteractiveElement {
         self
     }
 
+    /// Stop reporting a press on this element as a click once the mouse moves further than
+    /// `distance` from where it was pressed. By default, releasing the mouse anywhere over the
+    /// element is reported as a click unless the press started a drag.
+    fn cancel_click_beyond(mut self, distance: Pixels) -> Self {
+        self.interactivity().click_cancel_distance = Some(distance);
+        self
+    }
+
+    /// Bind the given callback to be called when the primary mouse button is held down over this
+    /// element for at least `duration` without the mouse moving away.
+    /// The fluent API equivalent to [`Interactivity::on_long_press`]
+    ///
+    /// See [`ViewContext::listener`](crate::ViewContext::listener) to get access to a view's state from this callback.
+    fn on_long_press(
+        mut self,
+        duration: Duration,
+        listener: impl Fn(&MouseDownEvent, &mut WindowContext) + 'static,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        self.interactivity().on_long_press(duration, listener);
+        self
+    }
+
     /// On drag initiation, this callback will be used to create a new view to render the dragged value for a
     /// drag and drop operation. This API should also be used as the equivalent of 'on drag start' with
     /// the [`Self::on_drag_move`] API
@@ -985,6 +1109,17 @@ pub trait StatefulInteractiveElement: InteractiveElement {
         self
     }
 
+    /// Only apply this element's hover style once the mouse has rested over it for `hover_in`,
+    /// and keep it applied until the mouse has been away for `hover_out`. This avoids flicker
+    /// when the mouse sweeps across dense lists of hoverable elements.
+    fn hover_delay(mut self, hover_in: Duration, hover_out: Duration) -> Self
+    where
+        Self: Sized,
+    {
+        self.interactivity().hover_delay = Some((hover_in, hover_out));
+        self
+    }
+
     /// Use the given callback to construct a new tooltip view when the mouse hovers over this element.
     /// The fluent API equivalent to [`Interactivity::tooltip`]
     fn tooltip(mut self, build_tooltip: impl Fn(&mut WindowContext) -> AnyView + 'static) -> Self
@@ -1029,6 +1164,18 @@ pub trait FocusableElement: InteractiveElement {
         self.interactivity().in_focus_style = Some(Box::new(f(StyleRefinement::default())));
         self
     }
+
+    /// Add this element to tab order at the given position, see [`WindowContext::focus_next`].
+    /// Focusable elements are only tab stops once they have an index. Elements with a positive
+    /// index come before those with an index of zero, and a negative index keeps the element
+    /// out of tab order.
+    fn tab_index(mut self, tab_index: isize) -> Self
+    where
+        Self: Sized,
+    {
+        self.interactivity().tab_index = Some(tab_index);
+        self
+    }
 }
 
 pub(crate) type MouseDownListener =
@@ -1042,8 +1189,15 @@ pub(crate) type MouseMoveListener =
 pub(crate) type ScrollWheelListener =
     Box<dyn Fn(&ScrollWheelEvent, DispatchPhase, &Hitbox, &mut WindowContext) + 'static>;
 
+pub(crate) type PinchListener =
+    Box<dyn Fn(&PinchEvent, DispatchPhase, &Hitbox, &mut WindowContext) + 'static>;
+
+pub(crate) type LayoutListener = Box<dyn Fn(Bounds<Pixels>, &mut WindowContext) + 'static>;
+
 pub(crate) type ClickListener = Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>;
 
+pub(crate) type LongPressListener = Rc<dyn Fn(&MouseDownEvent, &mut WindowContext) + 'static>;
+
 pub(crate) type DragListener = Box<dyn Fn(&dyn Any, &mut WindowContext) -> AnyView + 'static>;
 
 type DropListener = Box<dyn Fn(&dyn Any, &mut WindowContext) + 'static>;
@@ -1112,6 +1266,13 @@ impl InteractiveElement for Div {
     }
 }
 
+impl Disableable for Div {
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.interactivity.disabled = disabled;
+        self
+    }
+}
+
 impl ParentElement for Div {
     fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
         self.children.extend(elements)
@@ -1244,8 +1405,12 @@ pub struct Interactivity {
     pub(crate) key_context: Option<KeyContext>,
     pub(crate) focusable: bool,
     pub(crate) tracked_focus_handle: Option<FocusHandle>,
+    pub(crate) tab_index: Option<isize>,
     pub(crate) tracked_scroll_handle: Option<ScrollHandle>,
+    pub(crate) tracked_interaction: Option<InteractionState>,
     pub(crate) scroll_offset: Option<Rc<RefCell<Point<Pixels>>>>,
+    pub(crate) scroll_max: Size<Pixels>,
+    pub(crate) scrollbar_style: Option<ScrollbarStyle>,
     pub(crate) group: Option<SharedString>,
     /// The base style of the element, before any modifications are applied
     /// by focus, active, etc.
@@ -1253,9 +1418,12 @@ pub struct Interactivity {
     pub(crate) focus_style: Option<Box<StyleRefinement>>,
     pub(crate) in_focus_style: Option<Box<StyleRefinement>>,
     pub(crate) hover_style: Option<Box<StyleRefinement>>,
+    pub(crate) hover_delay: Option<(Duration, Duration)>,
     pub(crate) group_hover_style: Option<GroupStyle>,
     pub(crate) active_style: Option<Box<StyleRefinement>>,
     pub(crate) group_active_style: Option<GroupStyle>,
+    pub(crate) disabled: bool,
+    pub(crate) disabled_style: Option<Box<StyleRefinement>>,
     pub(crate) drag_over_styles: Vec<(
         TypeId,
         Box<dyn Fn(&dyn Any, &mut WindowContext) -> StyleRefinement>,
@@ -1265,6 +1433,8 @@ pub struct Interactivity {
     pub(crate) mouse_up_listeners: Vec<MouseUpListener>,
     pub(crate) mouse_move_listeners: Vec<MouseMoveListener>,
     pub(crate) scroll_wheel_listeners: Vec<ScrollWheelListener>,
+    pub(crate) pinch_listeners: Vec<PinchListener>,
+    pub(crate) layout_listeners: Vec<LayoutListener>,
     pub(crate) key_down_listeners: Vec<KeyDownListener>,
     pub(crate) key_up_listeners: Vec<KeyUpListener>,
     pub(crate) modifiers_changed_listeners: Vec<ModifiersChangedListener>,
@@ -1272,6 +1442,8 @@ pub struct Interactivity {
     pub(crate) drop_listeners: Vec<(TypeId, DropListener)>,
     pub(crate) can_drop_predicate: Option<CanDropPredicate>,
     pub(crate) click_listeners: Vec<ClickListener>,
+    pub(crate) click_cancel_distance: Option<Pixels>,
+    pub(crate) long_press_listener: Option<(Duration, LongPressListener)>,
     pub(crate) drag_listener: Option<(Box<dyn Any>, DragListener)>,
     pub(crate) hover_listener: Option<Box<dyn Fn(&bool, &mut WindowContext)>>,
     pub(crate) tooltip_builder: Option<TooltipBuilder>,
@@ -1359,6 +1531,10 @@ impl Interactivity {
         f: impl FnOnce(&Style, Point<Pixels>, Option<Hitbox>, &mut WindowContext) -> R,
     ) -> R {
         self.content_size = content_size;
+        for listener in self.layout_listeners.drain(..) {
+            listener(bounds, cx);
+        }
+
         cx.with_optional_element_state::<InteractiveElementState, _>(
             global_id,
             |element_state, cx| {
@@ -1383,7 +1559,8 @@ impl Interactivity {
 
                 cx.with_text_style(style.text_style().cloned(), |cx| {
                     cx.with_content_mask(style.overflow_mask(bounds, cx.rem_size()), |cx| {
-                        let hitbox = if self.should_insert_hitbox(&style) {
+                        let hitbox = if self.should_insert_hitbox(&style) || cx.inspector_enabled()
+                        {
                             Some(cx.insert_hitbox(bounds, self.occlude_mouse))
                         } else {
                             None
@@ -1404,13 +1581,16 @@ impl Interactivity {
             || self.group.is_some()
             || self.scroll_offset.is_some()
             || self.tracked_focus_handle.is_some()
+            || self.tracked_interaction.is_some()
             || self.hover_style.is_some()
             || self.group_hover_style.is_some()
             || !self.mouse_up_listeners.is_empty()
             || !self.mouse_down_listeners.is_empty()
             || !self.mouse_move_listeners.is_empty()
             || !self.click_listeners.is_empty()
+            || self.long_press_listener.is_some()
             || !self.scroll_wheel_listeners.is_empty()
+            || !self.pinch_listeners.is_empty()
             || self.drag_listener.is_some()
             || !self.drop_listeners.is_empty()
             || self.tooltip_builder.is_some()
@@ -1447,6 +1627,7 @@ impl Interactivity {
                         .to_pixels(bounds.size.height.into(), rem_size),
             );
             let scroll_max = (self.content_size + padding_size - bounds.size).max(&Size::default());
+            self.scroll_max = scroll_max;
             // Clamp scroll offset in case scroll max is smaller now (e.g., if children
             // were removed or the bounds became larger).
             let mut scroll_offset = scroll_offset.borrow_mut();
@@ -1497,35 +1678,72 @@ impl Interactivity {
                     return ((), element_state);
                 }
 
-                style.paint(bounds, cx, |cx: &mut WindowContext| {
-                    cx.with_text_style(style.text_style().cloned(), |cx| {
-                        cx.with_content_mask(style.overflow_mask(bounds, cx.rem_size()), |cx| {
-                            if let Some(hitbox) = hitbox {
-                                #[cfg(debug_assertions)]
-                                self.paint_debug_info(global_id, hitbox, &style, cx);
-
-                                if !cx.has_active_drag() {
-                                    if let Some(mouse_cursor) = style.mouse_cursor {
-                                        cx.set_cursor_style(mouse_cursor, hitbox);
+                cx.with_element_opacity(style.opacity, |cx| {
+                    style.paint(bounds, cx, |cx: &mut WindowContext| {
+                        cx.with_text_style(style.text_style().cloned(), |cx| {
+                            cx.with_content_mask(
+                                style.overflow_mask(bounds, cx.rem_size()),
+                                |cx| {
+                                    if let Some(hitbox) = hitbox {
+                                        #[cfg(debug_assertions)]
+                                        self.paint_debug_info(global_id, hitbox, &style, cx);
+
+                                        if hitbox.is_hovered(cx) {
+                                            cx.inspect_element(hitbox.bounds, || {
+                                                self.inspector_details(global_id, &style)
+                                            });
+                                        }
+
+                                        if !cx.has_active_drag() {
+                                            if let Some(mouse_cursor) = style.mouse_cursor {
+                                                cx.set_cursor_style(mouse_cursor, hitbox);
+                                            }
+                                        }
+
+                                        if let Some(group) = self.group.clone() {
+                                            GroupHitboxes::push(group, hitbox.id, cx);
+                                        }
+
+                                        if !self.disabled {
+                                            self.paint_mouse_listeners(
+                                                hitbox,
+                                                element_state.as_mut(),
+                                                cx,
+                                            );
+                                        } else if let Some(state) = self.tracked_interaction.take()
+                                        {
+                                            state.reset(cx);
+                                        }
+                                        self.paint_scroll_listener(hitbox, &style, cx);
                                     }
-                                }
-
-                                if let Some(group) = self.group.clone() {
-                                    GroupHitboxes::push(group, hitbox.id, cx);
-                                }
-
-                                self.paint_mouse_listeners(hitbox, element_state.as_mut(), cx);
-                                self.paint_scroll_listener(hitbox, &style, cx);
-                            }
 
-                            self.paint_keyboard_listeners(cx);
-                            f(&style, cx);
+                                    if !self.disabled {
+                                        if let Some((focus_handle, tab_index)) =
+                                            self.tracked_focus_handle.as_ref().zip(self.tab_index)
+                                        {
+                                            cx.insert_tab_stop(focus_handle, tab_index);
+                                        }
+                                        self.paint_keyboard_listeners(cx);
+                                    }
+                                    f(&style, cx);
+
+                                    if let Some(hitbox) = hitbox {
+                                        self.paint_scrollbars(
+                                            bounds,
+                                            hitbox,
+                                            &style,
+                                            element_state.as_mut(),
+                                            cx,
+                                        );
+                                    }
 
-                            if hitbox.is_some() {
-                                if let Some(group) = self.group.as_ref() {
-                                    GroupHitboxes::pop(group, cx);
-                                }
-                            }
+                                    if hitbox.is_some() {
+                                        if let Some(group) = self.group.as_ref() {
+                                            GroupHitboxes::pop(group, cx);
+                                        }
+                                    }
+                                },
+                            );
                         });
                     });
                 });
@@ -1646,12 +1864,94 @@ impl Interactivity {
         }
     }
 
+    /// Describes this element for the inspector overlay, see [`WindowContext::toggle_inspector`].
+    fn inspector_details(&self, global_id: Option<&GlobalElementId>, style: &Style) -> Vec<String> {
+        let mut details = Vec::new();
+        match global_id {
+            Some(global_id) => details.push(format!("{global_id:?}")),
+            None => details.push("<anonymous element>".to_string()),
+        }
+        #[cfg(debug_assertions)]
+        if let Some(location) = self.location {
+            details.push(format!(
+                "created at {}:{}:{}",
+                location.file(),
+                location.line(),
+                location.column()
+            ));
+        }
+
+        details.push(String::new());
+        details.push("Style".to_string());
+        details.push(format!(
+            "  display: {:?}, position: {:?}",
+            style.display, style.position
+        ));
+        details.push(format!("  size: {:?}", style.size));
+        details.push(format!("  min size: {:?}", style.min_size));
+        details.push(format!("  max size: {:?}", style.max_size));
+        details.push(format!("  margin: {:?}", style.margin));
+        details.push(format!("  padding: {:?}", style.padding));
+        details.push(format!("  border widths: {:?}", style.border_widths));
+        details.push(format!("  corner radii: {:?}", style.corner_radii));
+        details.push(format!("  background: {:?}", style.background));
+        details.push(format!("  border color: {:?}", style.border_color));
+        details.push(format!("  text color: {:?}", style.text.color));
+        details.push(format!("  opacity: {:?}", style.opacity));
+
+        let cascade = [
+            ("focus", self.focus_style.is_some()),
+            ("in focus", self.in_focus_style.is_some()),
+            ("hover", self.hover_style.is_some()),
+            ("group hover", self.group_hover_style.is_some()),
+            ("active", self.active_style.is_some()),
+            ("group active", self.group_active_style.is_some()),
+            ("disabled", self.disabled_style.is_some()),
+            ("drag over", !self.drag_over_styles.is_empty()),
+            ("group drag over", !self.group_drag_over_styles.is_empty()),
+        ];
+        details.push(String::new());
+        details.push("Cascade".to_string());
+        for (name, _) in cascade.iter().filter(|(_, present)| *present) {
+            details.push(format!("  {name}"));
+        }
+
+        let listeners = [
+            ("mouse down", self.mouse_down_listeners.len()),
+            ("mouse up", self.mouse_up_listeners.len()),
+            ("mouse move", self.mouse_move_listeners.len()),
+            ("scroll wheel", self.scroll_wheel_listeners.len()),
+            ("pinch", self.pinch_listeners.len()),
+            ("click", self.click_listeners.len()),
+            ("long press", self.long_press_listener.iter().count()),
+            ("hover", self.hover_listener.iter().count()),
+            ("drag", self.drag_listener.iter().count()),
+            ("drop", self.drop_listeners.len()),
+            ("key down", self.key_down_listeners.len()),
+            ("key up", self.key_up_listeners.len()),
+            ("modifiers changed", self.modifiers_changed_listeners.len()),
+            ("action", self.action_listeners.len()),
+            ("tooltip", self.tooltip_builder.iter().count()),
+        ];
+        details.push(String::new());
+        details.push("Listeners".to_string());
+        for (name, count) in listeners.iter().filter(|(_, count)| *count > 0) {
+            details.push(format!("  {name}: {count}"));
+        }
+
+        details
+    }
+
     fn paint_mouse_listeners(
         &mut self,
         hitbox: &Hitbox,
         element_state: Option<&mut InteractiveElementState>,
         cx: &mut WindowContext,
     ) {
+        if let Some(state) = self.tracked_interaction.take() {
+            state.paint_listeners(hitbox, cx);
+        }
+
         // If this element can be focused, register a mouse down listener
         // that will automatically transfer focus when hitting the element.
         // This behavior can be suppressed by using `cx.prevent_default()`.
@@ -1698,6 +1998,13 @@ impl Interactivity {
             })
         }
 
+        for listener in self.pinch_listeners.drain(..) {
+            let hitbox = hitbox.clone();
+            cx.on_mouse_event(move |event: &PinchEvent, phase, cx| {
+                listener(event, phase, &hitbox, cx);
+            })
+        }
+
         if self.hover_style.is_some()
             || self.base_style.mouse_cursor.is_some()
             || cx.active_drag.is_some() && !self.drag_over_styles.is_empty()
@@ -1715,6 +2022,7 @@ impl Interactivity {
         let mut drag_listener = mem::take(&mut self.drag_listener);
         let drop_listeners = mem::take(&mut self.drop_listeners);
         let click_listeners = mem::take(&mut self.click_listeners);
+        let click_cancel_distance = self.click_cancel_distance;
         let can_drop_predicate = mem::take(&mut self.can_drop_predicate);
 
         if !drop_listeners.is_empty() {
@@ -1785,10 +2093,8 @@ impl Interactivity {
 
                         let mut pending_mouse_down = pending_mouse_down.borrow_mut();
                         if let Some(mouse_down) = pending_mouse_down.clone() {
-                            if !cx.has_active_drag()
-                                && (event.position - mouse_down.position).magnitude()
-                                    > DRAG_THRESHOLD
-                            {
+                            let distance = (event.position - mouse_down.position).magnitude();
+                            if !cx.has_active_drag() && distance > DRAG_THRESHOLD {
                                 if let Some((drag_value, drag_listener)) = drag_listener.take() {
                                     *clicked_state.borrow_mut() = ElementClickedState::default();
                                     let cursor_offset = event.position - hitbox.origin;
@@ -1803,6 +2109,15 @@ impl Interactivity {
                                     cx.stop_propagation();
                                 }
                             }
+
+                            // A press that turned into a drag, or that wandered further than
+                            // the element allows from where it started, is no longer reported
+                            // as a click.
+                            let wandered_off = click_cancel_distance
+                                .map_or(false, |max_distance| distance > max_distance.0 as f64);
+                            if cx.has_active_drag() || wandered_off {
+                                pending_mouse_down.take();
+                            }
                         }
                     }
                 });
@@ -1837,6 +2152,76 @@ impl Interactivity {
                 });
             }
 
+            if let Some((duration, listener)) = self.long_press_listener.take() {
+                let pending_long_press = element_state
+                    .pending_long_press
+                    .get_or_insert_with(Default::default)
+                    .clone();
+                let pending_mouse_down = element_state
+                    .pending_mouse_down
+                    .get_or_insert_with(Default::default)
+                    .clone();
+
+                cx.on_mouse_event({
+                    let pending_long_press = pending_long_press.clone();
+                    let hitbox = hitbox.clone();
+                    move |event: &MouseDownEvent, phase, cx| {
+                        if phase == DispatchPhase::Bubble
+                            && event.button == MouseButton::Left
+                            && hitbox.is_hovered(cx)
+                        {
+                            let task = cx.spawn({
+                                let pending_long_press = pending_long_press.clone();
+                                let pending_mouse_down = pending_mouse_down.clone();
+                                let listener = listener.clone();
+                                move |mut cx| async move {
+                                    cx.background_executor().timer(duration).await;
+                                    let Some((mouse_down, _task)) =
+                                        pending_long_press.borrow_mut().take()
+                                    else {
+                                        return;
+                                    };
+                                    // The press has been consumed, so releasing the button
+                                    // should no longer be reported as a click.
+                                    pending_mouse_down.borrow_mut().take();
+                                    cx.update(|cx| listener(&mouse_down, cx)).ok();
+                                }
+                            });
+                            *pending_long_press.borrow_mut() = Some((event.clone(), task));
+                        }
+                    }
+                });
+
+                cx.on_mouse_event({
+                    let pending_long_press = pending_long_press.clone();
+                    let hitbox = hitbox.clone();
+                    move |event: &MouseMoveEvent, phase, cx| {
+                        if phase != DispatchPhase::Capture {
+                            return;
+                        }
+
+                        let mut pending_long_press = pending_long_press.borrow_mut();
+                        let moved_away =
+                            pending_long_press
+                                .as_ref()
+                                .map_or(false, |(mouse_down, _)| {
+                                    !hitbox.is_hovered(cx)
+                                        || (event.position - mouse_down.position).magnitude()
+                                            > DRAG_THRESHOLD
+                                });
+                        if moved_away {
+                            pending_long_press.take();
+                        }
+                    }
+                });
+
+                cx.on_mouse_event(move |_: &MouseUpEvent, phase, _| {
+                    if phase == DispatchPhase::Capture {
+                        pending_long_press.borrow_mut().take();
+                    }
+                });
+            }
+
             if let Some(hover_listener) = self.hover_listener.take() {
                 let hitbox = hitbox.clone();
                 let was_hovered = element_state
@@ -2084,6 +2469,148 @@ impl Interactivity {
         }
     }
 
+    fn paint_scrollbars(
+        &self,
+        bounds: Bounds<Pixels>,
+        hitbox: &Hitbox,
+        style: &Style,
+        element_state: Option<&mut InteractiveElementState>,
+        cx: &mut WindowContext,
+    ) {
+        let (Some(scrollbar_style), Some(scroll_offset), Some(element_state)) = (
+            self.scrollbar_style.clone(),
+            self.scroll_offset.clone(),
+            element_state,
+        ) else {
+            return;
+        };
+        let ScrollbarStyle {
+            thickness,
+            inset,
+            min_thumb_length,
+            thumb_color,
+            active_thumb_color,
+        } = scrollbar_style;
+        let scrollbar_drag = element_state
+            .scrollbar_drag
+            .get_or_insert_with(Default::default)
+            .clone();
+
+        let was_hovered = hitbox.is_hovered(cx);
+        cx.on_mouse_event({
+            let hitbox = hitbox.clone();
+            move |_: &MouseMoveEvent, phase, cx| {
+                if phase == DispatchPhase::Capture && hitbox.is_hovered(cx) != was_hovered {
+                    cx.refresh();
+                }
+            }
+        });
+
+        if !was_hovered && scrollbar_drag.borrow().is_none() {
+            return;
+        }
+
+        for axis in [Axis::Vertical, Axis::Horizontal] {
+            let overflow = match axis {
+                Axis::Vertical => style.overflow.y,
+                Axis::Horizontal => style.overflow.x,
+            };
+            let scroll_max = self.scroll_max.along(axis);
+            if overflow != Overflow::Scroll || scroll_max <= Pixels::ZERO {
+                continue;
+            }
+
+            let viewport_length = bounds.size.along(axis);
+            let track_length = viewport_length - inset * 2.;
+            let thumb_length = (track_length * (viewport_length / (viewport_length + scroll_max)))
+                .max(min_thumb_length)
+                .min(track_length);
+            let travel = track_length - thumb_length;
+            let progress = (-scroll_offset.borrow().along(axis) / scroll_max).clamp(0., 1.);
+            let thumb_start = inset + travel * progress;
+            let thumb_bounds = match axis {
+                Axis::Vertical => Bounds::new(
+                    point(
+                        bounds.right() - thickness - inset,
+                        bounds.top() + thumb_start,
+                    ),
+                    size(thickness, thumb_length),
+                ),
+                Axis::Horizontal => Bounds::new(
+                    point(
+                        bounds.left() + thumb_start,
+                        bounds.bottom() - thickness - inset,
+                    ),
+                    size(thumb_length, thickness),
+                ),
+            };
+
+            let dragging = scrollbar_drag
+                .borrow()
+                .as_ref()
+                .map_or(false, |drag| drag.axis == axis);
+            let color = if dragging {
+                active_thumb_color
+            } else {
+                thumb_color
+            };
+            cx.paint_quad(fill(thumb_bounds, color).corner_radii(thickness / 2.));
+
+            cx.on_mouse_event({
+                let scrollbar_drag = scrollbar_drag.clone();
+                let scroll_offset = scroll_offset.clone();
+                let hitbox = hitbox.clone();
+                move |event: &MouseDownEvent, phase, cx| {
+                    if phase == DispatchPhase::Bubble
+                        && event.button == MouseButton::Left
+                        && hitbox.is_hovered(cx)
+                        && thumb_bounds.contains(&event.position)
+                    {
+                        *scrollbar_drag.borrow_mut() = Some(ScrollbarDrag {
+                            axis,
+                            start_position: event.position.along(axis),
+                            start_offset: scroll_offset.borrow().along(axis),
+                        });
+                        cx.stop_propagation();
+                        cx.refresh();
+                    }
+                }
+            });
+
+            if dragging {
+                cx.on_mouse_event({
+                    let scrollbar_drag = scrollbar_drag.clone();
+                    let scroll_offset = scroll_offset.clone();
+                    move |event: &MouseMoveEvent, phase, cx| {
+                        if phase != DispatchPhase::Capture || travel <= Pixels::ZERO {
+                            return;
+                        }
+                        let Some(drag) = scrollbar_drag.borrow().clone() else {
+                            return;
+                        };
+
+                        let delta = event.position.along(axis) - drag.start_position;
+                        let offset = (drag.start_offset - delta * (scroll_max / travel))
+                            .clamp(-scroll_max, Pixels::ZERO);
+                        let mut scroll_offset = scroll_offset.borrow_mut();
+                        match axis {
+                            Axis::Vertical => scroll_offset.y = offset,
+                            Axis::Horizontal => scroll_offset.x = offset,
+                        }
+                        cx.stop_propagation();
+                        cx.refresh();
+                    }
+                });
+            }
+        }
+
+        cx.on_mouse_event(move |_: &MouseUpEvent, phase, cx| {
+            if phase == DispatchPhase::Capture && scrollbar_drag.borrow_mut().take().is_some() {
+                cx.refresh();
+            }
+        });
+    }
+
     /// Compute the visual style for this element, based on the current bounds and the element's state.
     pub fn compute_style(
         &self,
@@ -2103,7 +2630,7 @@ impl Interactivity {
     fn compute_style_internal(
         &self,
         hitbox: Option<&Hitbox>,
-        element_state: Option<&mut InteractiveElementState>,
+        mut element_state: Option<&mut InteractiveElementState>,
         cx: &mut WindowContext,
     ) -> Style {
         let mut style = Style::default();
@@ -2124,7 +2651,7 @@ impl Interactivity {
         }
 
         if let Some(hitbox) = hitbox {
-            if !cx.has_active_drag() {
+            if !cx.has_active_drag() && !self.disabled {
                 if let Some(group_hover) = self.group_hover_style.as_ref() {
                     if let Some(group_hitbox_id) =
                         GroupHitboxes::get(&group_hover.group, cx.deref_mut())
@@ -2136,7 +2663,19 @@ impl Interactivity {
                 }
 
                 if let Some(hover_style) = self.hover_style.as_ref() {
-                    if hitbox.is_hovered(cx) {
+                    let mut is_hovered = hitbox.is_hovered(cx);
+                    if let Some((hover_delay, element_state)) =
+                        self.hover_delay.zip(element_state.as_deref_mut())
+                    {
+                        let delayed_hover_state = element_state
+                            .delayed_hover_state
+                            .get_or_insert_with(Default::default)
+                            .clone();
+                        is_hovered =
+                            delayed_hover(is_hovered, hover_delay, &delayed_hover_state, cx);
+                    }
+
+                    if is_hovered {
                         style.refine(hover_style);
                     }
                 }
@@ -2172,7 +2711,7 @@ impl Interactivity {
             }
         }
 
-        if let Some(element_state) = element_state {
+        if let Some(element_state) = element_state.filter(|_| !self.disabled) {
             let clicked_state = element_state
                 .clicked_state
                 .get_or_insert_with(Default::default)
@@ -2190,10 +2729,101 @@ impl Interactivity {
             }
         }
 
+        if self.disabled {
+            if let Some(disabled_style) = self.disabled_style.as_ref() {
+                style.refine(disabled_style);
+            }
+        }
+
         style
     }
 }
 
+/// A handle to the hover and active state of an element, see
+/// [`InteractiveElement::track_interaction`]. Clones of the handle share the same state.
+#[derive(Clone, Default)]
+pub struct InteractionState {
+    hovered: Rc<Cell<bool>>,
+    active: Rc<Cell<bool>>,
+}
+
+impl InteractionState {
+    /// Construct a new interaction state handle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the mouse was over the tracked element when it was last painted
+    /// or moved.
+    pub fn is_hovered(&self) -> bool {
+        self.hovered.get()
+    }
+
+    /// Returns whether the primary mouse button is being held down on the tracked element.
+    pub fn is_active(&self) -> bool {
+        self.active.get()
+    }
+
+    /// Clears the state of an element that no longer receives mouse events, such as one that was
+    /// disabled while hovered or pressed.
+    fn reset(self, cx: &mut WindowContext) {
+        let was_hovered = self.hovered.replace(false);
+        let was_active = self.active.replace(false);
+        if was_hovered || was_active {
+            Self::notifier(cx)(cx);
+        }
+    }
+
+    fn notifier(cx: &mut WindowContext) -> impl Fn(&mut WindowContext) + Copy + 'static {
+        let parent_view_id = cx.parent_view_id();
+        move |cx: &mut WindowContext| {
+            if let Some(parent_view_id) = parent_view_id {
+                cx.notify(parent_view_id);
+            } else {
+                cx.refresh();
+            }
+        }
+    }
+
+    fn paint_listeners(self, hitbox: &Hitbox, cx: &mut WindowContext) {
+        self.hovered.set(hitbox.is_hovered(cx));
+
+        let notify = Self::notifier(cx);
+
+        cx.on_mouse_event({
+            let hovered = self.hovered.clone();
+            let hitbox = hitbox.clone();
+            move |_: &MouseMoveEvent, phase, cx| {
+                let is_hovered = hitbox.is_hovered(cx);
+                if phase == DispatchPhase::Capture && hovered.replace(is_hovered) != is_hovered {
+                    notify(cx);
+                }
+            }
+        });
+
+        cx.on_mouse_event({
+            let active = self.active.clone();
+            let hitbox = hitbox.clone();
+            move |event: &MouseDownEvent, phase, cx| {
+                if phase == DispatchPhase::Bubble
+                    && event.button == MouseButton::Left
+                    && hitbox.is_hovered(cx)
+                    && !active.replace(true)
+                {
+                    notify(cx);
+                }
+            }
+        });
+
+        let active = self.active;
+        cx.on_mouse_event(move |_: &MouseUpEvent, phase, cx| {
+            if phase == DispatchPhase::Capture && active.replace(false) {
+                notify(cx);
+            }
+        });
+    }
+}
+
 /// The per-frame state of an interactive element. Used for tracking stateful interactions like clicks
 /// and scroll offsets.
 #[derive(Default)]
@@ -2201,11 +2831,97 @@ pub struct InteractiveElementState {
     pub(crate) focus_handle: Option<FocusHandle>,
     pub(crate) clicked_state: Option<Rc<RefCell<ElementClickedState>>>,
     pub(crate) hover_state: Option<Rc<RefCell<bool>>>,
+    pub(crate) delayed_hover_state: Option<Rc<RefCell<DelayedHoverState>>>,
     pub(crate) pending_mouse_down: Option<Rc<RefCell<Option<MouseDownEvent>>>>,
+    pub(crate) scrollbar_drag: Option<Rc<RefCell<Option<ScrollbarDrag>>>>,
+    pub(crate) pending_long_press: Option<Rc<RefCell<Option<(MouseDownEvent, Task<()>)>>>>,
     pub(crate) scroll_offset: Option<Rc<RefCell<Point<Pixels>>>>,
     pub(crate) active_tooltip: Option<Rc<RefCell<Option<ActiveTooltip>>>>,
 }
 
+/// The size and colors of the scrollbars painted by [`StatefulInteractiveElement::scrollbar_style`].
+#[derive(Clone, Debug)]
+pub struct ScrollbarStyle {
+    /// The thickness of the thumb across the scrolling axis.
+    pub thickness: Pixels,
+    /// The gap between the thumb and the edges of the element.
+    pub inset: Pixels,
+    /// The shortest the thumb gets along the scrolling axis, however long the content is.
+    pub min_thumb_length: Pixels,
+    /// The color of the thumb.
+    pub thumb_color: Hsla,
+    /// The color of the thumb while it's being dragged.
+    pub active_thumb_color: Hsla,
+}
+
+impl Default for ScrollbarStyle {
+    fn default() -> Self {
+        Self {
+            thickness: px(6.),
+            inset: px(2.),
+            min_thumb_length: px(16.),
+            thumb_color: hsla(0., 0., 0.5, 0.5),
+            active_thumb_color: hsla(0., 0., 0.5, 0.8),
+        }
+    }
+}
+
+/// The scrollbar thumb being dragged, see [`StatefulInteractiveElement::scrollbar`].
+#[derive(Clone)]
+pub(crate) struct ScrollbarDrag {
+    axis: Axis,
+    start_position: Pixels,
+    start_offset: Pixels,
+}
+
+/// Tracks whether a hover style with a delay is applied, see
+/// [`StatefulInteractiveElement::hover_delay`].
+#[derive(Default)]
+pub(crate) struct DelayedHoverState {
+    hovered: bool,
+    pending: Option<(bool, Task<()>)>,
+}
+
+/// Returns whether a delayed hover style should be applied, scheduling a refresh
+/// for when the delay elapses if the hover state has just changed.
+fn delayed_hover(
+    is_hovered: bool,
+    (hover_in, hover_out): (Duration, Duration),
+    state: &Rc<RefCell<DelayedHoverState>>,
+    cx: &mut WindowContext,
+) -> bool {
+    let mut delayed = state.borrow_mut();
+    if delayed.hovered == is_hovered {
+        delayed.pending = None;
+        return is_hovered;
+    }
+
+    let delay = if is_hovered { hover_in } else { hover_out };
+    if delay.is_zero() {
+        delayed.hovered = is_hovered;
+        delayed.pending = None;
+        return is_hovered;
+    }
+
+    if delayed
+        .pending
+        .as_ref()
+        .map_or(true, |(pending_hovered, _)| *pending_hovered != is_hovered)
+    {
+        let task = cx.spawn({
+            let state = state.clone();
+            move |mut cx| async move {
+                cx.background_executor().timer(delay).await;
+                state.borrow_mut().hovered = is_hovered;
+                cx.update(|cx| cx.refresh()).ok();
+            }
+        });
+        delayed.pending = Some((is_hovered, task));
+    }
+
+    delayed.hovered
+}
+
 /// The current active tooltip
 pub struct ActiveTooltip {
     pub(crate) tooltip: Option<AnyTooltip>,
@@ -2272,6 +2988,16 @@ where
     }
 }
 
+impl<E> Disableable for Focusable<E>
+where
+    E: InteractiveElement,
+{
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.interactivity().disabled = disabled;
+        self
+    }
+}
+
 impl<E: StatefulInteractiveElement> StatefulInteractiveElement for Focusable<E> {}
 
 impl<E> Styled for Focusable<E>
@@ -2374,6 +3100,16 @@ where
     }
 }
 
+impl<E> Disableable for Stateful<E>
+where
+    E: InteractiveElement,
+{
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.interactivity().disabled = disabled;
+        self
+    }
+}
+
 impl<E: FocusableElement> FocusableElement for Stateful<E> {}
 
 impl<E> Element for Stateful<E>
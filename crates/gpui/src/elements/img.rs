@@ -3,10 +3,10 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::{
-    point, px, size, AbsoluteLength, Asset, Bounds, DefiniteLength, DevicePixels, Element,
-    ElementId, GlobalElementId, Hitbox, ImageData, InteractiveElement, Interactivity, IntoElement,
-    LayoutId, Length, Pixels, SharedUri, Size, StyleRefinement, Styled, SvgSize, UriOrPath,
-    WindowContext,
+    point, px, size, AbsoluteLength, AnyElement, Asset, Bounds, DefiniteLength, DevicePixels,
+    Element, ElementId, GlobalElementId, Hitbox, ImageData, InteractiveElement, Interactivity,
+    IntoElement, LayoutId, Length, Pixels, SharedUri, Size, StyleRefinement, Styled, SvgSize,
+    UriOrPath, WindowContext,
 };
 use futures::{AsyncReadExt, Future};
 use image::{ImageBuffer, ImageError};
@@ -81,6 +81,8 @@ pub struct Img {
     source: ImageSource,
     grayscale: bool,
     object_fit: ObjectFit,
+    loading: Option<Box<dyn Fn() -> AnyElement>>,
+    fallback: Option<Box<dyn Fn() -> AnyElement>>,
 }
 
 /// Create a new image element.
@@ -90,6 +92,8 @@ pub fn img(source: impl Into<ImageSource>) -> Img {
         source: source.into(),
         grayscale: false,
         object_fit: ObjectFit::Contain,
+        loading: None,
+        fallback: None,
     }
 }
 
@@ -228,10 +232,22 @@ impl Img {
         self.object_fit = object_fit;
         self
     }
+
+    /// Set an element to be displayed in place of the image while it is loading.
+    pub fn with_loading(mut self, loading: impl Fn() -> AnyElement + 'static) -> Self {
+        self.loading = Some(Box::new(loading));
+        self
+    }
+
+    /// Set an element to be displayed in place of the image if it fails to load.
+    pub fn with_fallback(mut self, fallback: impl Fn() -> AnyElement + 'static) -> Self {
+        self.fallback = Some(Box::new(fallback));
+        self
+    }
 }
 
 impl Element for Img {
-    type RequestLayoutState = ();
+    type RequestLayoutState = Option<AnyElement>;
     type PrepaintState = Option<Hitbox>;
 
     fn id(&self) -> Option<ElementId> {
@@ -243,56 +259,90 @@ impl Element for Img {
         global_id: Option<&GlobalElementId>,
         cx: &mut WindowContext,
     ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut placeholder = None;
         let layout_id = self
             .interactivity
             .request_layout(global_id, cx, |mut style, cx| {
-                if let Some(data) = self.source.data(cx) {
-                    let image_size = data.size();
-                    match (style.size.width, style.size.height) {
-                        (Length::Auto, Length::Auto) => {
-                            style.size = Size {
-                                width: Length::Definite(DefiniteLength::Absolute(
-                                    AbsoluteLength::Pixels(px(image_size.width.0 as f32)),
-                                )),
-                                height: Length::Definite(DefiniteLength::Absolute(
-                                    AbsoluteLength::Pixels(px(image_size.height.0 as f32)),
-                                )),
+                let placeholder_builder = match self.source.use_data(cx) {
+                    Some(Ok(data)) => {
+                        let image_size = data.size();
+                        match (style.size.width, style.size.height) {
+                            (Length::Auto, Length::Auto) => {
+                                style.size = Size {
+                                    width: Length::Definite(DefiniteLength::Absolute(
+                                        AbsoluteLength::Pixels(px(image_size.width.0 as f32)),
+                                    )),
+                                    height: Length::Definite(DefiniteLength::Absolute(
+                                        AbsoluteLength::Pixels(px(image_size.height.0 as f32)),
+                                    )),
+                                }
                             }
+                            (Length::Auto, _) | (_, Length::Auto) => {
+                                // Keep the image's proportions when only one dimension is set.
+                                if style.aspect_ratio.is_none() && image_size.height.0 > 0 {
+                                    style.aspect_ratio = Some(
+                                        image_size.width.0 as f32 / image_size.height.0 as f32,
+                                    );
+                                }
+                            }
+                            _ => {}
                         }
-                        _ => {}
+                        None
+                    }
+                    Some(Err(_)) => self.fallback.as_ref(),
+                    None if matches!(self.source, ImageSource::Uri(_) | ImageSource::File(_)) => {
+                        self.loading.as_ref()
                     }
+                    None => None,
+                };
+
+                let mut child_layout_ids = Vec::new();
+                if let Some(placeholder_builder) = placeholder_builder {
+                    let mut element = placeholder_builder();
+                    child_layout_ids.push(element.request_layout(cx));
+                    placeholder = Some(element);
                 }
 
-                cx.request_layout(style, [])
+                cx.request_layout(style, child_layout_ids)
             });
-        (layout_id, ())
+        (layout_id, placeholder)
     }
 
     fn prepaint(
         &mut self,
         global_id: Option<&GlobalElementId>,
         bounds: Bounds<Pixels>,
-        _request_layout: &mut Self::RequestLayoutState,
+        placeholder: &mut Self::RequestLayoutState,
         cx: &mut WindowContext,
     ) -> Option<Hitbox> {
         self.interactivity
-            .prepaint(global_id, bounds, bounds.size, cx, |_, _, hitbox, _| hitbox)
+            .prepaint(global_id, bounds, bounds.size, cx, |_, _, hitbox, cx| {
+                if let Some(placeholder) = placeholder.as_mut() {
+                    placeholder.prepaint(cx);
+                }
+                hitbox
+            })
     }
 
     fn paint(
         &mut self,
         global_id: Option<&GlobalElementId>,
         bounds: Bounds<Pixels>,
-        _: &mut Self::RequestLayoutState,
+        placeholder: &mut Self::RequestLayoutState,
         hitbox: &mut Self::PrepaintState,
         cx: &mut WindowContext,
     ) {
         let source = self.source.clone();
         self.interactivity
             .paint(global_id, bounds, hitbox.as_ref(), cx, |style, cx| {
+                if let Some(placeholder) = placeholder.as_mut() {
+                    placeholder.paint(cx);
+                    return;
+                }
+
                 let corner_radii = style.corner_radii.to_pixels(bounds.size, cx.rem_size());
 
-                if let Some(data) = source.data(cx) {
+                if let Some(data) = source.use_data(cx).and_then(|data| data.log_err()) {
                     let new_bounds = self.object_fit.get_bounds(bounds, data.size());
                     cx.paint_image(new_bounds, corner_radii, data.clone(), self.grayscale)
                         .log_err();
@@ -333,7 +383,8 @@ impl InteractiveElement for Img {
 }
 
 impl ImageSource {
-    fn data(&self, cx: &mut WindowContext) -> Option<Arc<ImageData>> {
+    /// Returns the decoded image data, or `None` if it is still loading.
+    fn use_data(&self, cx: &mut WindowContext) -> Option<Result<Arc<ImageData>, ImageCacheError>> {
         match self {
             ImageSource::Uri(_) | ImageSource::File(_) => {
                 let uri_or_path: UriOrPath = match self {
@@ -342,10 +393,10 @@ impl ImageSource {
                     _ => unreachable!(),
                 };
 
-                cx.use_cached_asset::<Image>(&uri_or_path)?.log_err()
+                cx.use_cached_asset::<Image>(&uri_or_path)
             }
 
-            ImageSource::Data(data) => Some(data.to_owned()),
+            ImageSource::Data(data) => Some(Ok(data.to_owned())),
             #[cfg(target_os = "macos")]
             ImageSource::Surface(_) => None,
         }
@@ -449,3 +500,102 @@ impl From<usvg::Error> for ImageCacheError {
         Self::Usvg(Arc::new(error))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        self as gpui, blue, div, red, Background, Hsla, ParentElement, Render, TestAppContext,
+        VisualTestContext,
+    };
+    use futures::channel::oneshot;
+    use http::{AsyncBody, FakeHttpClient, Response};
+    use parking_lot::Mutex;
+    use std::io::Cursor;
+
+    struct ImageView {
+        source: SharedUri,
+    }
+
+    impl Render for ImageView {
+        fn render(&mut self, _: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            div().child(
+                img(self.source.clone())
+                    .size(px(20.))
+                    .with_loading(|| div().size_full().bg(red()).into_any_element())
+                    .with_fallback(|| div().size_full().bg(blue()).into_any_element()),
+            )
+        }
+    }
+
+    fn png_bytes() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image::RgbaImage::new(2, 2)
+            .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    /// Draws a frame and returns whether the loading element, the fallback and the image
+    /// were painted.
+    fn painted(cx: &mut VisualTestContext) -> (bool, bool, bool) {
+        cx.run_until_parked();
+        cx.update(|cx| {
+            cx.draw();
+            let scene = &cx.window.rendered_frame.scene;
+            let has_quad = |color: Hsla| {
+                scene
+                    .quads
+                    .iter()
+                    .any(|quad| quad.background == Background::from(color))
+            };
+            (
+                has_quad(red()),
+                has_quad(blue()),
+                !scene.polychrome_sprites.is_empty(),
+            )
+        })
+    }
+
+    #[gpui::test]
+    fn test_loading_and_fallback(cx: &mut TestAppContext) {
+        let (release_tx, release_rx) = oneshot::channel::<()>();
+        let release_rx = Arc::new(Mutex::new(Some(release_rx)));
+        cx.update(|cx| {
+            cx.update_http_client(FakeHttpClient::create(move |request| {
+                let release_rx = release_rx.clone();
+                async move {
+                    if request.uri().path() == "/missing.png" {
+                        return Ok(Response::builder()
+                            .status(404)
+                            .body(AsyncBody::default())
+                            .unwrap());
+                    }
+                    let release_rx = release_rx.lock().take();
+                    if let Some(release_rx) = release_rx {
+                        release_rx.await.ok();
+                    }
+                    Ok(Response::builder()
+                        .status(200)
+                        .body(png_bytes().into())
+                        .unwrap())
+                }
+            }));
+        });
+
+        // The loading element is shown while the image is pending, and replaced by the image
+        // once it has loaded.
+        let (_, image_cx) = cx.add_window_view(|_| ImageView {
+            source: "https://example.com/image.png".into(),
+        });
+        assert_eq!(painted(image_cx), (true, false, false));
+        release_tx.send(()).unwrap();
+        assert_eq!(painted(image_cx), (false, false, true));
+
+        // The fallback is shown in place of an image that fails to load.
+        let (_, missing_cx) = cx.add_window_view(|_| ImageView {
+            source: "https://example.com/missing.png".into(),
+        });
+        assert_eq!(painted(missing_cx), (false, true, false));
+    }
+}
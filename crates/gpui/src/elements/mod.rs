@@ -5,6 +5,7 @@ mod deferred;
 mod div;
 mod img;
 mod list;
+mod resizable;
 mod svg;
 mod text;
 mod uniform_list;
@@ -16,6 +17,7 @@ pub use deferred::*;
 pub use div::*;
 pub use img::*;
 pub use list::*;
+pub use resizable::*;
 pub use svg::*;
 pub use text::*;
 pub use uniform_list::*;
@@ -0,0 +1,378 @@
+//! A container that splits its space between exactly two children along an axis, separated
+//! by a divider that can be dragged to resize them. The position of the divider is stored
+//! in the element's state, so it persists across frames while the element keeps its id.
+
+use crate::{
+    fill, px, relative, size, Along, AnyElement, Axis, Bounds, CursorStyle, DispatchPhase, Element,
+    ElementId, GlobalElementId, Hitbox, Hsla, IntoElement, LayoutId, MouseButton, MouseDownEvent,
+    MouseMoveEvent, MouseUpEvent, ParentElement, Pixels, Style, WindowContext,
+};
+use smallvec::SmallVec;
+use std::{cell::Cell, rc::Rc};
+use taffy::style::{Display, FlexDirection};
+
+/// Creates a split container that lays out its two children along the given axis, with a
+/// draggable divider between them. [`Axis::Horizontal`] places the children side by side,
+/// [`Axis::Vertical`] stacks them. The element panics if it doesn't have exactly two children.
+///
+/// The divider is transparent unless a color is given with [`Resizable::divider`].
+pub fn resizable(id: impl Into<ElementId>, axis: Axis) -> Resizable {
+    Resizable {
+        id: id.into(),
+        axis,
+        children: SmallVec::new(),
+        initial_ratio: 0.5,
+        min_size: px(0.),
+        max_size: None,
+        divider_size: px(1.),
+        divider_color: None,
+    }
+}
+
+/// A split element with a draggable divider, see [`resizable`].
+pub struct Resizable {
+    id: ElementId,
+    axis: Axis,
+    children: SmallVec<[AnyElement; 2]>,
+    initial_ratio: f32,
+    min_size: Pixels,
+    max_size: Option<Pixels>,
+    divider_size: Pixels,
+    divider_color: Option<Hsla>,
+}
+
+impl Resizable {
+    /// Sets the fraction of the available space given to the first child until the divider
+    /// is dragged. Defaults to an even split.
+    pub fn initial_ratio(mut self, ratio: f32) -> Self {
+        self.initial_ratio = ratio.clamp(0., 1.);
+        self
+    }
+
+    /// Sets the smallest size either child can be resized to along the split axis.
+    pub fn min_size(mut self, min_size: impl Into<Pixels>) -> Self {
+        self.min_size = min_size.into();
+        self
+    }
+
+    /// Sets the largest size the first child can be resized to along the split axis.
+    pub fn max_size(mut self, max_size: impl Into<Pixels>) -> Self {
+        self.max_size = Some(max_size.into());
+        self
+    }
+
+    /// Sets the thickness and color of the divider.
+    pub fn divider(mut self, size: impl Into<Pixels>, color: impl Into<Hsla>) -> Self {
+        self.divider_size = size.into();
+        self.divider_color = Some(color.into());
+        self
+    }
+
+    fn pane_style(&self, grow: f32) -> Style {
+        Style {
+            display: Display::Flex,
+            flex_basis: px(0.).into(),
+            flex_grow: grow,
+            flex_shrink: 1.,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ResizableState {
+    ratio: Rc<Cell<f32>>,
+    dragging: Rc<Cell<bool>>,
+}
+
+/// Layout state for a [`Resizable`] element.
+pub struct ResizableLayoutState {
+    divider: LayoutId,
+    state: ResizableState,
+}
+
+/// Prepaint state for a [`Resizable`] element.
+pub struct ResizablePrepaintState {
+    divider_bounds: Bounds<Pixels>,
+    divider_hitbox: Hitbox,
+}
+
+impl Element for Resizable {
+    type RequestLayoutState = ResizableLayoutState;
+    type PrepaintState = ResizablePrepaintState;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let initial_ratio = self.initial_ratio;
+        let state =
+            cx.with_element_state(global_id.unwrap(), |state: Option<ResizableState>, _cx| {
+                let state = state.unwrap_or_else(|| ResizableState {
+                    ratio: Rc::new(Cell::new(initial_ratio)),
+                    dragging: Rc::default(),
+                });
+                (state.clone(), state)
+            });
+
+        let ratio = state.ratio.get();
+        let mut first_style = self.pane_style(ratio);
+        let mut second_style = self.pane_style(1. - ratio);
+        first_style.min_size = first_style
+            .min_size
+            .apply_along(self.axis, |_| self.min_size.into());
+        second_style.min_size = second_style
+            .min_size
+            .apply_along(self.axis, |_| self.min_size.into());
+        if let Some(max_size) = self.max_size {
+            first_style.max_size = first_style
+                .max_size
+                .apply_along(self.axis, |_| max_size.into());
+        }
+
+        let divider_style = Style {
+            flex_shrink: 0.,
+            size: Style::default()
+                .size
+                .apply_along(self.axis, |_| self.divider_size.into()),
+            ..Default::default()
+        };
+
+        let [first, second] = &mut self.children[..] else {
+            panic!(
+                "resizable element must have exactly two children, found {}",
+                self.children.len()
+            );
+        };
+        let first = first.request_layout(cx);
+        let second = second.request_layout(cx);
+
+        let first = cx.request_layout(first_style, [first]);
+        let divider = cx.request_layout(divider_style, None);
+        let second = cx.request_layout(second_style, [second]);
+
+        let root_style = Style {
+            display: Display::Flex,
+            flex_direction: match self.axis {
+                Axis::Horizontal => FlexDirection::Row,
+                Axis::Vertical => FlexDirection::Column,
+            },
+            size: size(relative(1.).into(), relative(1.).into()),
+            ..Default::default()
+        };
+        let layout_id = cx.request_layout(root_style, [first, divider, second]);
+
+        (layout_id, ResizableLayoutState { divider, state })
+    }
+
+    fn prepaint(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        request_layout: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        for child in &mut self.children {
+            child.prepaint(cx);
+        }
+
+        let divider_bounds = cx.layout_bounds(request_layout.divider);
+        let mut hit_bounds = divider_bounds;
+        hit_bounds.dilate(px(2.));
+        let divider_hitbox = cx.insert_hitbox(hit_bounds, false);
+
+        ResizablePrepaintState {
+            divider_bounds,
+            divider_hitbox,
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        request_layout: &mut Self::RequestLayoutState,
+        prepaint: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        for child in &mut self.children {
+            child.paint(cx);
+        }
+
+        let state = &request_layout.state;
+        let hitbox = &prepaint.divider_hitbox;
+        if let Some(divider_color) = self.divider_color {
+            cx.paint_quad(fill(prepaint.divider_bounds, divider_color));
+        }
+        cx.set_cursor_style(
+            match self.axis {
+                Axis::Horizontal => CursorStyle::ResizeLeftRight,
+                Axis::Vertical => CursorStyle::ResizeUpDown,
+            },
+            hitbox,
+        );
+
+        let parent_view_id = cx.parent_view_id();
+        let notify = move |cx: &mut WindowContext| {
+            if let Some(parent_view_id) = parent_view_id {
+                cx.notify(parent_view_id);
+            } else {
+                cx.refresh();
+            }
+        };
+
+        cx.on_mouse_event({
+            let dragging = state.dragging.clone();
+            let hitbox = hitbox.clone();
+            move |event: &MouseDownEvent, phase, cx| {
+                if phase == DispatchPhase::Bubble
+                    && event.button == MouseButton::Left
+                    && hitbox.is_hovered(cx)
+                {
+                    dragging.set(true);
+                    cx.stop_propagation();
+                }
+            }
+        });
+
+        cx.on_mouse_event({
+            let axis = self.axis;
+            let divider_size = self.divider_size;
+            let min_size = self.min_size;
+            let max_size = self.max_size;
+            let state = state.clone();
+            move |event: &MouseMoveEvent, phase, cx| {
+                if phase != DispatchPhase::Capture || !state.dragging.get() {
+                    return;
+                }
+                if !event.dragging() {
+                    state.dragging.set(false);
+                    return;
+                }
+
+                let available = bounds.size.along(axis) - divider_size;
+                if available <= px(0.) {
+                    return;
+                }
+
+                let mut first =
+                    event.position.along(axis) - bounds.origin.along(axis) - divider_size / 2.;
+                if let Some(max_size) = max_size {
+                    first = first.min(max_size);
+                }
+                first = first.clamp(min_size, (available - min_size).max(min_size));
+
+                state.ratio.set((first / available).clamp(0., 1.));
+                cx.stop_propagation();
+                notify(cx);
+            }
+        });
+
+        cx.on_mouse_event({
+            let dragging = state.dragging.clone();
+            move |event: &MouseUpEvent, phase, _cx| {
+                if phase == DispatchPhase::Capture && event.button == MouseButton::Left {
+                    dragging.set(false);
+                }
+            }
+        });
+    }
+}
+
+impl IntoElement for Resizable {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl ParentElement for Resizable {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        self as gpui, div, point, px, resizable, Axis, Bounds, InteractiveElement, IntoElement,
+        Modifiers, MouseButton, ParentElement, Pixels, Render, Styled, TestAppContext,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    struct SplitView {
+        first_bounds: Rc<Cell<Option<Bounds<Pixels>>>>,
+    }
+
+    impl Render for SplitView {
+        fn render(&mut self, _: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            let first_bounds = self.first_bounds.clone();
+            div().w(px(201.)).h(px(100.)).child(
+                resizable("split", Axis::Horizontal)
+                    .min_size(px(20.))
+                    .max_size(px(150.))
+                    .child(
+                        div()
+                            .size_full()
+                            .on_layout(move |bounds, _| first_bounds.set(Some(bounds))),
+                    )
+                    .child(div().size_full()),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_drag_divider(cx: &mut TestAppContext) {
+        let first_bounds = Rc::new(Cell::new(None));
+        let (_, cx) = cx.add_window_view(|_| SplitView {
+            first_bounds: first_bounds.clone(),
+        });
+        let first_width = |cx: &mut gpui::VisualTestContext| {
+            cx.update(|cx| cx.draw());
+            first_bounds.get().unwrap().size.width
+        };
+        assert_eq!(first_width(cx), px(100.));
+
+        // The divider sits between the two panes, dragging it resizes the first one.
+        cx.simulate_mouse_down(
+            point(px(100.5), px(50.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_mouse_move(
+            point(px(60.5), px(50.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        assert_eq!(first_width(cx), px(60.));
+
+        // Dragging is clamped to the minimum and maximum sizes.
+        cx.simulate_mouse_move(
+            point(px(5.), px(50.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        assert_eq!(first_width(cx), px(20.));
+        cx.simulate_mouse_move(
+            point(px(195.), px(50.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        assert_eq!(first_width(cx), px(150.));
+
+        // Releasing the mouse ends the drag.
+        cx.simulate_mouse_up(
+            point(px(195.), px(50.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_mouse_move(point(px(60.5), px(50.)), None, Modifiers::default());
+        assert_eq!(first_width(cx), px(150.));
+    }
+}
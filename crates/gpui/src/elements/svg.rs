@@ -10,6 +10,7 @@ pub struct Svg {
     interactivity: Interactivity,
     transformation: Option<Transformation>,
     path: Option<SharedString>,
+    inherit_text_color: bool,
 }
 
 /// Create a new SVG element.
@@ -18,6 +19,7 @@ pub fn svg() -> Svg {
         interactivity: Interactivity::default(),
         transformation: None,
         path: None,
+        inherit_text_color: false,
     }
 }
 
@@ -28,6 +30,14 @@ impl Svg {
         self
     }
 
+    /// Tint the SVG with the text color cascaded from its ancestors when the element doesn't
+    /// set a text color of its own, like `currentColor` in CSS. Without this, an SVG without
+    /// a text color isn't painted.
+    pub fn inherit_text_color(mut self) -> Self {
+        self.inherit_text_color = true;
+        self
+    }
+
     /// Transform the SVG element with the given transformation.
     /// Note that this won't effect the hitbox or layout of the element, only the rendering.
     pub fn with_transformation(mut self, transformation: Transformation) -> Self {
@@ -78,7 +88,11 @@ impl Element for Svg {
     {
         self.interactivity
             .paint(global_id, bounds, hitbox.as_ref(), cx, |style, cx| {
-                if let Some((path, color)) = self.path.as_ref().zip(style.text.color) {
+                let color = style
+                    .text
+                    .color
+                    .or_else(|| self.inherit_text_color.then(|| cx.text_style().color));
+                if let Some((path, color)) = self.path.as_ref().zip(color) {
                     let transformation = self
                         .transformation
                         .as_ref()
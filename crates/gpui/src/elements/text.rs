@@ -1,8 +1,8 @@
 use crate::{
     ActiveTooltip, AnyTooltip, AnyView, Bounds, DispatchPhase, Element, ElementId, GlobalElementId,
-    HighlightStyle, Hitbox, IntoElement, LayoutId, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
-    Pixels, Point, SharedString, Size, TextRun, TextStyle, WhiteSpace, WindowContext, WrappedLine,
-    TOOLTIP_DELAY,
+    HighlightStyle, Hitbox, IntoElement, LayoutId, LineLayout, MouseDownEvent, MouseMoveEvent,
+    MouseUpEvent, Pixels, Point, SharedString, Size, TextOverflow, TextRun, TextStyle, WhiteSpace,
+    WindowContext, WrappedLine, TOOLTIP_DELAY,
 };
 use anyhow::anyhow;
 use parking_lot::{Mutex, MutexGuard};
@@ -240,6 +240,9 @@ struct TextLayoutInner {
     lines: SmallVec<[WrappedLine; 1]>,
     line_height: Pixels,
     wrap_width: Option<Pixels>,
+    truncate_width: Option<Pixels>,
+    line_clamp: Option<usize>,
+    truncation: Option<TruncationMap>,
     size: Option<Size<Pixels>>,
     bounds: Option<Bounds<Pixels>>,
 }
@@ -271,24 +274,29 @@ impl TextLayout {
             let element_state = self.clone();
 
             move |known_dimensions, available_space, cx| {
+                let width = known_dimensions.width.or(match available_space.width {
+                    crate::AvailableSpace::Definite(x) => Some(x),
+                    _ => None,
+                });
                 let wrap_width = if text_style.white_space == WhiteSpace::Normal {
-                    known_dimensions.width.or(match available_space.width {
-                        crate::AvailableSpace::Definite(x) => Some(x),
-                        _ => None,
-                    })
+                    width
                 } else {
                     None
                 };
+                let truncate_width = text_style.text_overflow.and(width);
+                let line_clamp = text_style.line_clamp;
 
                 if let Some(text_layout) = element_state.0.lock().as_ref() {
                     if text_layout.size.is_some()
                         && (wrap_width.is_none() || wrap_width == text_layout.wrap_width)
+                        && truncate_width == text_layout.truncate_width
+                        && line_clamp == text_layout.line_clamp
                     {
                         return text_layout.size.unwrap();
                     }
                 }
 
-                let Some(lines) = cx
+                let mut lines = cx
                     .text_system()
                     .shape_text(
                         text.clone(),
@@ -296,12 +304,35 @@ impl TextLayout {
                         &runs,
                         wrap_width, // Wrap if we know the width.
                     )
-                    .log_err()
-                else {
+                    .log_err();
+
+                let mut truncation = None;
+                if let Some(shaped_lines) = lines.as_ref() {
+                    if let Some((truncated_text, truncated_runs, truncation_map)) = truncate_lines(
+                        shaped_lines,
+                        &text,
+                        &runs,
+                        line_clamp,
+                        text_style.text_overflow.zip(truncate_width),
+                        font_size,
+                        cx,
+                    ) {
+                        lines = cx
+                            .text_system()
+                            .shape_text(truncated_text, font_size, &truncated_runs, wrap_width)
+                            .log_err();
+                        truncation = Some(truncation_map);
+                    }
+                }
+
+                let Some(lines) = lines else {
                     element_state.lock().replace(TextLayoutInner {
                         lines: Default::default(),
                         line_height,
                         wrap_width,
+                        truncate_width,
+                        line_clamp,
+                        truncation: None,
                         size: Some(Size::default()),
                         bounds: None,
                     });
@@ -319,6 +350,9 @@ impl TextLayout {
                     lines,
                     line_height,
                     wrap_width,
+                    truncate_width,
+                    line_clamp,
+                    truncation,
                     size: Some(size),
                     bounds: None,
                 });
@@ -358,12 +392,28 @@ impl TextLayout {
         }
     }
 
-    /// Get the byte index into the input of the pixel position.
-    pub fn index_for_position(&self, mut position: Point<Pixels>) -> Result<usize, usize> {
+    /// Get the byte index into the input of the pixel position. When the text is truncated,
+    /// positions over the overflow string map to the index where the input was cut off.
+    pub fn index_for_position(&self, position: Point<Pixels>) -> Result<usize, usize> {
         let element_state = self.lock();
         let element_state = element_state
             .as_ref()
             .expect("measurement has not been performed");
+        let to_original = |ix| {
+            element_state
+                .truncation
+                .as_ref()
+                .map_or(ix, |truncation| truncation.to_original(ix))
+        };
+        Self::displayed_index_for_position(element_state, position)
+            .map(to_original)
+            .map_err(to_original)
+    }
+
+    fn displayed_index_for_position(
+        element_state: &TextLayoutInner,
+        mut position: Point<Pixels>,
+    ) -> Result<usize, usize> {
         let bounds = element_state
             .bounds
             .expect("prepaint has not been performed");
@@ -392,12 +442,17 @@ impl TextLayout {
         Err(line_start_ix.saturating_sub(1))
     }
 
-    /// Get the pixel position for the given byte index.
+    /// Get the pixel position for the given byte index. When the text is truncated, indices
+    /// in the hidden part of the input map to the position where it was cut off.
     pub fn position_for_index(&self, index: usize) -> Option<Point<Pixels>> {
         let element_state = self.lock();
         let element_state = element_state
             .as_ref()
             .expect("measurement has not been performed");
+        let index = element_state
+            .truncation
+            .as_ref()
+            .map_or(index, |truncation| truncation.to_truncated(index));
         let bounds = element_state
             .bounds
             .expect("prepaint has not been performed");
@@ -447,6 +502,217 @@ impl TextLayout {
     }
 }
 
+/// Maps byte indices between text and its truncated form, see [`truncate_lines`].
+#[derive(Clone, Debug, Default, PartialEq)]
+struct TruncationMap {
+    /// The slices of the original text that were kept, in order, along with where each one
+    /// starts in the truncated text. Anything between them was replaced or removed.
+    segments: Vec<(usize, Range<usize>)>,
+}
+
+impl TruncationMap {
+    /// Maps an index in the truncated text to the original text. Indices within an overflow
+    /// string map to the end of the slice it follows.
+    fn to_original(&self, ix: usize) -> usize {
+        let segment_ix = self
+            .segments
+            .partition_point(|(truncated_start, _)| *truncated_start <= ix);
+        let Some((truncated_start, original)) = segment_ix
+            .checked_sub(1)
+            .and_then(|segment_ix| self.segments.get(segment_ix))
+        else {
+            return 0;
+        };
+        (original.start + (ix - truncated_start)).min(original.end)
+    }
+
+    /// Maps an index in the original text to the truncated text. Indices that were cut off
+    /// map to the end of the preceding slice that was kept.
+    fn to_truncated(&self, ix: usize) -> usize {
+        let segment_ix = self
+            .segments
+            .partition_point(|(_, original)| original.start <= ix);
+        let Some((truncated_start, original)) = segment_ix
+            .checked_sub(1)
+            .and_then(|segment_ix| self.segments.get(segment_ix))
+        else {
+            return 0;
+        };
+        truncated_start + (ix.min(original.end) - original.start)
+    }
+}
+
+/// Truncates shaped text to at most `line_clamp` visual lines, and replaces the end of any
+/// visible line that doesn't fit within the truncation width with the overflow string.
+/// Returns `None` if the text can be displayed as it was shaped.
+fn truncate_lines(
+    lines: &[WrappedLine],
+    text: &str,
+    runs: &[TextRun],
+    line_clamp: Option<usize>,
+    overflow: Option<(TextOverflow, Pixels)>,
+    font_size: Pixels,
+    cx: &WindowContext,
+) -> Option<(SharedString, Vec<TextRun>, TruncationMap)> {
+    struct VisualLine<'a> {
+        layout: &'a LineLayout,
+        line_start_ix: usize,
+        range: Range<usize>,
+        start_x: Pixels,
+        width: Pixels,
+        ends_with_newline: bool,
+    }
+
+    let mut visual_lines = Vec::new();
+    let mut line_start_ix = 0;
+    for (line_ix, line) in lines.iter().enumerate() {
+        let layout = line.unwrapped_layout.as_ref();
+        let mut start_ix = 0;
+        let mut start_x = Pixels::ZERO;
+        for boundary in line.wrap_boundaries() {
+            let glyph = &layout.runs[boundary.run_ix].glyphs[boundary.glyph_ix];
+            visual_lines.push(VisualLine {
+                layout,
+                line_start_ix,
+                range: line_start_ix + start_ix..line_start_ix + glyph.index,
+                start_x,
+                width: glyph.position.x - start_x,
+                ends_with_newline: false,
+            });
+            start_ix = glyph.index;
+            start_x = glyph.position.x;
+        }
+        visual_lines.push(VisualLine {
+            layout,
+            line_start_ix,
+            range: line_start_ix + start_ix..line_start_ix + layout.len,
+            start_x,
+            width: layout.width - start_x,
+            ends_with_newline: line_ix + 1 < lines.len(),
+        });
+        line_start_ix += layout.len + 1;
+    }
+
+    let clamped = line_clamp.map_or(false, |line_clamp| visual_lines.len() > line_clamp);
+    if let Some(line_clamp) = line_clamp {
+        visual_lines.truncate(line_clamp);
+    }
+    let visible_line_count = visual_lines.len();
+    let overflows = |ix: usize, visual_line: &VisualLine| {
+        overflow.map_or(false, |(_, truncate_width)| {
+            visual_line.width > truncate_width || (clamped && ix + 1 == visible_line_count)
+        })
+    };
+    if !clamped
+        && !visual_lines
+            .iter()
+            .enumerate()
+            .any(|(ix, visual_line)| overflows(ix, visual_line))
+    {
+        return None;
+    }
+
+    let mut truncated_text = String::with_capacity(text.len());
+    let mut truncated_runs = Vec::with_capacity(runs.len());
+    let mut truncation = TruncationMap::default();
+    for (ix, visual_line) in visual_lines.iter().enumerate() {
+        let mut range = visual_line.range.clone();
+        let mut suffix = None;
+        if let Some((TextOverflow::Ellipsis(ellipsis), truncate_width)) =
+            overflow.filter(|_| overflows(ix, visual_line))
+        {
+            let ellipsis_width = run_at(runs, range.end.saturating_sub(1))
+                .and_then(|run| {
+                    let run = TextRun {
+                        len: ellipsis.len(),
+                        ..run.clone()
+                    };
+                    cx.text_system()
+                        .layout_line(ellipsis, font_size, &[run])
+                        .log_err()
+                })
+                .map_or(Pixels::ZERO, |layout| layout.width);
+            let available_width = truncate_width - ellipsis_width;
+
+            if visual_line.width > available_width {
+                let mut end_ix = range.start;
+                for glyph in visual_line.layout.runs.iter().flat_map(|run| &run.glyphs) {
+                    let glyph_ix = visual_line.line_start_ix + glyph.index;
+                    if glyph_ix < range.start {
+                        continue;
+                    }
+                    if glyph_ix >= range.end
+                        || glyph.position.x - visual_line.start_x > available_width
+                    {
+                        break;
+                    }
+                    end_ix = glyph_ix;
+                }
+                range.end = end_ix;
+            }
+            range.end = range.start + text[range.clone()].trim_end().len();
+            suffix = Some(ellipsis);
+        }
+
+        truncation
+            .segments
+            .push((truncated_text.len(), range.clone()));
+        truncated_text.push_str(&text[range.clone()]);
+        push_runs(runs, range.clone(), &mut truncated_runs);
+        if let Some(suffix) = suffix {
+            if let Some(run) = run_at(runs, range.end.saturating_sub(1)) {
+                truncated_text.push_str(suffix);
+                truncated_runs.push(TextRun {
+                    len: suffix.len(),
+                    ..run.clone()
+                });
+            }
+        }
+        if visual_line.ends_with_newline && ix + 1 < visible_line_count {
+            truncation.segments.push((
+                truncated_text.len(),
+                visual_line.range.end..visual_line.range.end + 1,
+            ));
+            truncated_text.push('\n');
+            push_runs(
+                runs,
+                visual_line.range.end..visual_line.range.end + 1,
+                &mut truncated_runs,
+            );
+        }
+    }
+
+    Some((truncated_text.into(), truncated_runs, truncation))
+}
+
+fn run_at(runs: &[TextRun], ix: usize) -> Option<&TextRun> {
+    let mut run_start = 0;
+    for run in runs {
+        run_start += run.len;
+        if ix < run_start {
+            return Some(run);
+        }
+    }
+    runs.last()
+}
+
+fn push_runs(runs: &[TextRun], range: Range<usize>, target: &mut Vec<TextRun>) {
+    let mut run_start = 0;
+    for run in runs {
+        let run_end = run_start + run.len;
+        let len = run_end
+            .min(range.end)
+            .saturating_sub(run_start.max(range.start));
+        if len > 0 {
+            target.push(TextRun { len, ..run.clone() });
+        }
+        if run_end >= range.end {
+            break;
+        }
+        run_start = run_end;
+    }
+}
+
 /// A text element that can be interacted with.
 pub struct InteractiveText {
     element_id: ElementId,
@@ -726,3 +992,94 @@ impl IntoElement for InteractiveText {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{font, px, TestAppContext};
+
+    fn truncate(
+        text: &str,
+        line_clamp: Option<usize>,
+        width_in_chars: Option<f32>,
+        cx: &mut TestAppContext,
+    ) -> Option<(String, TruncationMap)> {
+        cx.text_system()
+            .add_fonts(vec![std::fs::read(
+                "../../assets/fonts/plex-mono/ZedPlexMono-Regular.ttf",
+            )
+            .unwrap()
+            .into()])
+            .unwrap();
+        let cx = cx.add_empty_window();
+        cx.update(|cx| {
+            let font_size = px(10.);
+            let run = TextRun {
+                len: text.len(),
+                font: font("Zed Plex Mono"),
+                color: Default::default(),
+                background_color: None,
+                underline: None,
+                strikethrough: None,
+            };
+            let char_width = cx
+                .text_system()
+                .layout_line(
+                    "a",
+                    font_size,
+                    &[TextRun {
+                        len: 1,
+                        ..run.clone()
+                    }],
+                )
+                .unwrap()
+                .width;
+            let lines = cx
+                .text_system()
+                .shape_text(text.to_string().into(), font_size, &[run.clone()], None)
+                .unwrap();
+            let overflow = width_in_chars
+                .map(|width_in_chars| (TextOverflow::Ellipsis("..."), char_width * width_in_chars));
+            truncate_lines(&lines, text, &[run], line_clamp, overflow, font_size, cx).map(
+                |(truncated_text, truncated_runs, truncation)| {
+                    assert_eq!(
+                        truncated_runs.iter().map(|run| run.len).sum::<usize>(),
+                        truncated_text.len()
+                    );
+                    (truncated_text.to_string(), truncation)
+                },
+            )
+        })
+    }
+
+    #[crate::test]
+    fn test_truncate_with_ellipsis(cx: &mut TestAppContext) {
+        assert_eq!(truncate("abcdefghij", None, Some(20.), cx), None);
+
+        // The ellipsis takes the place of the characters that don't fit.
+        let (truncated, truncation) = truncate("abcdefghij", None, Some(7.5), cx).unwrap();
+        assert_eq!(truncated, "abcd...");
+        assert_eq!(truncation.to_original(2), 2);
+        assert_eq!(truncation.to_original(5), 4);
+        assert_eq!(truncation.to_truncated(8), 4);
+    }
+
+    #[crate::test]
+    fn test_truncate_with_line_clamp(cx: &mut TestAppContext) {
+        let (truncated, truncation) = truncate("one\ntwo\nthree", Some(2), None, cx).unwrap();
+        assert_eq!(truncated, "one\ntwo");
+        assert_eq!(truncation.to_original(5), 5);
+        assert_eq!(truncation.to_truncated(10), 7);
+
+        // The last visible line of clamped text ends with an ellipsis, even if it fits.
+        let (truncated, _) = truncate("one\ntwo\nthree", Some(2), Some(20.), cx).unwrap();
+        assert_eq!(truncated, "one\ntwo...");
+    }
+
+    #[crate::test]
+    fn test_truncate_multibyte_text(cx: &mut TestAppContext) {
+        let (truncated, truncation) = truncate("äöüäöüäöü", None, Some(5.5), cx).unwrap();
+        assert_eq!(truncated, "äö...");
+        assert_eq!(truncation.to_original("äö.".len()), "äö".len());
+    }
+}
@@ -147,6 +147,28 @@ pub struct ClickEvent {
     pub up: MouseUpEvent,
 }
 
+impl ClickEvent {
+    /// The mouse button that was clicked.
+    pub fn button(&self) -> MouseButton {
+        self.down.button
+    }
+
+    /// The number of consecutive clicks, e.g. 2 for a double click.
+    pub fn click_count(&self) -> usize {
+        self.up.click_count
+    }
+
+    /// The modifiers that were held down when the button was pressed.
+    pub fn modifiers(&self) -> Modifiers {
+        self.down.modifiers
+    }
+
+    /// The position of the mouse on the window when the button was pressed.
+    pub fn position(&self) -> Point<Pixels> {
+        self.down.position
+    }
+}
+
 /// An enum representing the mouse button that was pressed.
 #[derive(Hash, PartialEq, Eq, Copy, Clone, Debug)]
 pub enum MouseButton {
@@ -258,6 +280,43 @@ impl Deref for ScrollWheelEvent {
     }
 }
 
+/// A trackpad pinch (magnification) gesture from the platform.
+///
+/// Only the macOS platform reports pinch gestures for now; on Linux and Windows no
+/// `PinchEvent`s are dispatched, so pinch-to-zoom should have a keyboard or scroll
+/// wheel equivalent.
+#[derive(Clone, Debug, Default)]
+pub struct PinchEvent {
+    /// The position of the mouse on the window.
+    pub position: Point<Pixels>,
+
+    /// The change in magnification for this event. Positive values zoom in and
+    /// negative values zoom out, e.g. a delta of 0.1 corresponds to a 10% zoom in.
+    pub delta: f32,
+
+    /// The modifiers that were held down during the gesture.
+    pub modifiers: Modifiers,
+
+    /// The phase of the touch event.
+    pub touch_phase: TouchPhase,
+}
+
+impl Sealed for PinchEvent {}
+impl InputEvent for PinchEvent {
+    fn to_platform_input(self) -> PlatformInput {
+        PlatformInput::Pinch(self)
+    }
+}
+impl MouseEvent for PinchEvent {}
+
+impl Deref for PinchEvent {
+    type Target = Modifiers;
+
+    fn deref(&self) -> &Self::Target {
+        &self.modifiers
+    }
+}
+
 /// The scroll delta for a scroll wheel event.
 #[derive(Clone, Copy, Debug)]
 pub enum ScrollDelta {
@@ -429,6 +488,8 @@ pub enum PlatformInput {
     MouseExited(MouseExitEvent),
     /// The scroll wheel was used.
     ScrollWheel(ScrollWheelEvent),
+    /// A pinch gesture was performed on the trackpad.
+    Pinch(PinchEvent),
     /// Files were dragged and dropped onto the window.
     FileDrop(FileDropEvent),
 }
@@ -444,6 +505,7 @@ impl PlatformInput {
             PlatformInput::MouseMove(event) => Some(event),
             PlatformInput::MouseExited(event) => Some(event),
             PlatformInput::ScrollWheel(event) => Some(event),
+            PlatformInput::Pinch(event) => Some(event),
             PlatformInput::FileDrop(event) => Some(event),
         }
     }
@@ -458,6 +520,7 @@ impl PlatformInput {
             PlatformInput::MouseMove(_) => None,
             PlatformInput::MouseExited(_) => None,
             PlatformInput::ScrollWheel(_) => None,
+            PlatformInput::Pinch(_) => None,
             PlatformInput::FileDrop(_) => None,
         }
     }
@@ -467,17 +530,22 @@ impl PlatformInput {
 mod test {
 
     use crate::{
-        self as gpui, div, FocusHandle, InteractiveElement, IntoElement, KeyBinding, Keystroke,
-        ParentElement, Render, TestAppContext, VisualContext,
+        self as gpui, blue, div, point, px, red, size, Background, Bounds, Disableable,
+        FocusHandle, FocusableElement, InteractionState, InteractiveElement, IntoElement,
+        KeyBinding, Keystroke, Modifiers, MouseButton, ParentElement, PinchEvent, Pixels, Render,
+        ScrollHandle, ScrollbarStyle, StatefulInteractiveElement, Styled, TestAppContext,
+        TouchPhase, VisualContext, VisualTestContext,
     };
 
+    use std::{cell::Cell, rc::Rc, time::Duration};
+
     struct TestView {
         saw_key_down: bool,
         saw_action: bool,
         focus_handle: FocusHandle,
     }
 
-    actions!(test, [TestAction]);
+    actions!(test, [TestAction, FocusNext, FocusPrev]);
 
     impl Render for TestView {
         fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
@@ -535,4 +603,559 @@ mod test {
             })
             .unwrap();
     }
+
+    struct TabOrderView {
+        first: FocusHandle,
+        skipped: FocusHandle,
+        untracked: FocusHandle,
+        explicit: FocusHandle,
+    }
+
+    impl Render for TabOrderView {
+        fn render(&mut self, _: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            div()
+                .on_action(|_: &FocusNext, cx| cx.focus_next())
+                .on_action(|_: &FocusPrev, cx| cx.focus_prev())
+                .child(div().track_focus(&self.first).tab_index(0))
+                .child(div().track_focus(&self.skipped).tab_index(-1))
+                .child(div().track_focus(&self.untracked))
+                .child(div().track_focus(&self.explicit).tab_index(1))
+        }
+    }
+
+    #[gpui::test]
+    fn test_focus_traversal(cx: &mut TestAppContext) {
+        let window = cx.update(|cx| {
+            cx.open_window(Default::default(), |cx| {
+                cx.new_view(|cx| TabOrderView {
+                    first: cx.focus_handle(),
+                    skipped: cx.focus_handle(),
+                    untracked: cx.focus_handle(),
+                    explicit: cx.focus_handle(),
+                })
+            })
+            .unwrap()
+        });
+
+        cx.update(|cx| {
+            cx.bind_keys(vec![
+                KeyBinding::new("tab", FocusNext, None),
+                KeyBinding::new("shift-tab", FocusPrev, None),
+            ]);
+        });
+
+        window.update(cx, |view, cx| cx.focus(&view.first)).unwrap();
+
+        let assert_focused =
+            |cx: &mut TestAppContext, expected: fn(&TabOrderView) -> &FocusHandle| {
+                window
+                    .update(cx, |view, cx| {
+                        assert!(expected(view).is_focused(cx));
+                        assert!(!view.skipped.is_focused(cx));
+                        assert!(!view.untracked.is_focused(cx));
+                    })
+                    .unwrap();
+            };
+
+        cx.dispatch_keystroke(*window, Keystroke::parse("tab").unwrap());
+        assert_focused(cx, |view| &view.explicit);
+        cx.dispatch_keystroke(*window, Keystroke::parse("tab").unwrap());
+        assert_focused(cx, |view| &view.first);
+        cx.dispatch_keystroke(*window, Keystroke::parse("shift-tab").unwrap());
+        assert_focused(cx, |view| &view.explicit);
+        cx.dispatch_keystroke(*window, Keystroke::parse("shift-tab").unwrap());
+        assert_focused(cx, |view| &view.first);
+    }
+
+    struct ClickView {
+        clicks: usize,
+        cancel_distance: Option<Pixels>,
+    }
+
+    impl Render for ClickView {
+        fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            let mut target = div()
+                .id("target")
+                .size_full()
+                .on_click(cx.listener(|this, _, _| this.clicks += 1));
+            if let Some(distance) = self.cancel_distance {
+                target = target.cancel_click_beyond(distance);
+            }
+            target
+        }
+    }
+
+    #[gpui::test]
+    fn test_click_not_cancelled_by_default(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_| ClickView {
+            clicks: 0,
+            cancel_distance: None,
+        });
+        let start = point(px(10.), px(10.));
+
+        cx.simulate_mouse_down(start, MouseButton::Left, Modifiers::default());
+        cx.simulate_mouse_move(
+            point(px(100.), px(100.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_mouse_up(start, MouseButton::Left, Modifiers::default());
+        view.update(cx, |view, _| assert_eq!(view.clicks, 1));
+    }
+
+    #[gpui::test]
+    fn test_click_cancelled_by_drag(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_| ClickView {
+            clicks: 0,
+            cancel_distance: Some(px(8.)),
+        });
+        let start = point(px(10.), px(10.));
+
+        cx.simulate_mouse_down(start, MouseButton::Left, Modifiers::default());
+        cx.simulate_mouse_move(
+            point(px(100.), px(100.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_mouse_move(start, MouseButton::Left, Modifiers::default());
+        cx.simulate_mouse_up(start, MouseButton::Left, Modifiers::default());
+        view.update(cx, |view, _| assert_eq!(view.clicks, 0));
+
+        cx.simulate_mouse_down(start, MouseButton::Left, Modifiers::default());
+        cx.simulate_mouse_move(
+            point(px(12.), px(11.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_mouse_up(start, MouseButton::Left, Modifiers::default());
+        view.update(cx, |view, _| assert_eq!(view.clicks, 1));
+    }
+
+    struct DisabledView {
+        disabled: bool,
+        clicks: usize,
+        key_downs: usize,
+        focus_handle: FocusHandle,
+        interaction: InteractionState,
+    }
+
+    impl Render for DisabledView {
+        fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            div()
+                .id("target")
+                .size_full()
+                .track_focus(&self.focus_handle)
+                .track_interaction(&self.interaction)
+                .disabled(self.disabled)
+                .on_click(cx.listener(|this, _, _| this.clicks += 1))
+                .on_key_down(cx.listener(|this, _, _| this.key_downs += 1))
+        }
+    }
+
+    #[gpui::test]
+    fn test_disabled(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|cx| DisabledView {
+            disabled: true,
+            clicks: 0,
+            key_downs: 0,
+            focus_handle: cx.focus_handle(),
+            interaction: InteractionState::new(),
+        });
+        view.update(cx, |view, cx| cx.focus(&view.focus_handle));
+        cx.update(|cx| cx.draw());
+
+        let position = point(px(10.), px(10.));
+        let interact = |cx: &mut VisualTestContext| {
+            cx.simulate_mouse_down(position, MouseButton::Left, Modifiers::default());
+            cx.simulate_mouse_up(position, MouseButton::Left, Modifiers::default());
+            cx.simulate_keystrokes("a");
+        };
+
+        // A disabled element registers none of its listeners.
+        interact(cx);
+        view.update(cx, |view, _| {
+            assert_eq!(view.clicks, 0);
+            assert_eq!(view.key_downs, 0);
+        });
+
+        // Once it is enabled again, the same events reach its listeners.
+        view.update(cx, |view, cx| {
+            view.disabled = false;
+            cx.notify();
+        });
+        cx.update(|cx| cx.draw());
+        interact(cx);
+        view.update(cx, |view, _| {
+            assert_eq!(view.clicks, 1);
+            assert_eq!(view.key_downs, 1);
+        });
+
+        // Disabling the element while it is hovered and pressed clears its tracked state.
+        cx.simulate_mouse_down(position, MouseButton::Left, Modifiers::default());
+        view.update(cx, |view, _| {
+            assert!(view.interaction.is_hovered());
+            assert!(view.interaction.is_active());
+        });
+        view.update(cx, |view, cx| {
+            view.disabled = true;
+            cx.notify();
+        });
+        cx.update(|cx| cx.draw());
+        view.update(cx, |view, _| {
+            assert!(!view.interaction.is_hovered());
+            assert!(!view.interaction.is_active());
+        });
+    }
+
+    struct PinchView {
+        zoom: f32,
+    }
+
+    impl Render for PinchView {
+        fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            div().size_full().child(
+                div()
+                    .w(px(100.))
+                    .h(px(100.))
+                    .on_pinch(cx.listener(|this, event: &PinchEvent, _| this.zoom += event.delta)),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_on_pinch(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_| PinchView { zoom: 1. });
+        let pinch = |position, delta| PinchEvent {
+            position,
+            delta,
+            modifiers: Modifiers::default(),
+            touch_phase: TouchPhase::Moved,
+        };
+
+        cx.simulate_event(pinch(point(px(50.), px(50.)), 0.5));
+        cx.simulate_event(pinch(point(px(50.), px(50.)), -0.25));
+        view.update(cx, |view, _| assert_eq!(view.zoom, 1.25));
+
+        // Pinching outside of the element doesn't reach its listener.
+        cx.simulate_event(pinch(point(px(150.), px(150.)), 0.5));
+        view.update(cx, |view, _| assert_eq!(view.zoom, 1.25));
+    }
+
+    struct InspectView;
+
+    impl Render for InspectView {
+        fn render(&mut self, _: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            div().size_full().child(
+                div()
+                    .id("inspected")
+                    .absolute()
+                    .top(px(10.))
+                    .left(px(20.))
+                    .w(px(30.))
+                    .h(px(40.))
+                    .flex(),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_inspect_hovered_element(cx: &mut TestAppContext) {
+        let (_, cx) = cx.add_window_view(|_| InspectView);
+        cx.update(|cx| cx.toggle_inspector());
+        cx.simulate_mouse_move(point(px(25.), px(25.)), None, Modifiers::default());
+        cx.update(|cx| cx.draw());
+
+        cx.update(|cx| {
+            let (bounds, details) = cx.inspected_element().unwrap();
+            assert_eq!(
+                bounds,
+                Bounds::new(point(px(20.), px(10.)), size(px(30.), px(40.)))
+            );
+            assert!(details[0].contains("inspected"));
+            assert!(details
+                .iter()
+                .any(|line| line == "  display: Flex, position: Absolute"));
+        });
+
+        // Nothing is reported once the inspector is disabled again.
+        cx.update(|cx| cx.toggle_inspector());
+        cx.update(|cx| cx.draw());
+        cx.update(|cx| assert!(cx.inspected_element().is_none()));
+    }
+
+    struct HoverDelayView;
+
+    impl Render for HoverDelayView {
+        fn render(&mut self, _: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            div().size_full().child(
+                div()
+                    .id("target")
+                    .w(px(100.))
+                    .h(px(100.))
+                    .bg(red())
+                    .hover(|style| style.bg(blue()))
+                    .hover_delay(Duration::from_millis(100), Duration::from_millis(200)),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_hover_delay(cx: &mut TestAppContext) {
+        let (_, cx) = cx.add_window_view(|_| HoverDelayView);
+        let inside = point(px(50.), px(50.));
+        let outside = point(px(150.), px(150.));
+
+        let hover_painted = |cx: &mut VisualTestContext| {
+            cx.run_until_parked();
+            cx.update(|cx| {
+                cx.draw();
+                cx.window
+                    .rendered_frame
+                    .scene
+                    .quads
+                    .iter()
+                    .any(|quad| quad.background == Background::from(blue()))
+            })
+        };
+        let advance = |cx: &mut VisualTestContext, millis| {
+            cx.executor().advance_clock(Duration::from_millis(millis));
+        };
+
+        // The hover style is applied once the mouse has rested over the element.
+        cx.simulate_mouse_move(inside, None, Modifiers::default());
+        assert!(!hover_painted(cx));
+        advance(cx, 50);
+        assert!(!hover_painted(cx));
+        advance(cx, 60);
+        assert!(hover_painted(cx));
+
+        // It stays applied until the mouse has been away for the hover-out delay.
+        cx.simulate_mouse_move(outside, None, Modifiers::default());
+        assert!(hover_painted(cx));
+        advance(cx, 150);
+        assert!(hover_painted(cx));
+        advance(cx, 60);
+        assert!(!hover_painted(cx));
+
+        // Leaving before the hover-in delay elapses cancels the pending hover.
+        cx.simulate_mouse_move(inside, None, Modifiers::default());
+        assert!(!hover_painted(cx));
+        advance(cx, 50);
+        cx.simulate_mouse_move(outside, None, Modifiers::default());
+        assert!(!hover_painted(cx));
+        advance(cx, 200);
+        assert!(!hover_painted(cx));
+    }
+
+    struct LongPressView {
+        long_presses: usize,
+        clicks: usize,
+    }
+
+    impl Render for LongPressView {
+        fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            div()
+                .id("target")
+                .w(px(100.))
+                .h(px(100.))
+                .on_long_press(
+                    Duration::from_millis(500),
+                    cx.listener(|this, _, _| this.long_presses += 1),
+                )
+                .on_click(cx.listener(|this, _, _| this.clicks += 1))
+        }
+    }
+
+    #[gpui::test]
+    fn test_on_long_press(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_| LongPressView {
+            long_presses: 0,
+            clicks: 0,
+        });
+        let start = point(px(10.), px(10.));
+        let advance = |cx: &mut VisualTestContext, millis| {
+            cx.executor().advance_clock(Duration::from_millis(millis));
+        };
+
+        // Holding the button down for the duration fires the listener, and the release is no
+        // longer reported as a click.
+        cx.simulate_mouse_down(start, MouseButton::Left, Modifiers::default());
+        advance(cx, 499);
+        view.update(cx, |view, _| assert_eq!(view.long_presses, 0));
+        advance(cx, 1);
+        view.update(cx, |view, _| assert_eq!(view.long_presses, 1));
+        cx.simulate_mouse_up(start, MouseButton::Left, Modifiers::default());
+        view.update(cx, |view, _| assert_eq!(view.clicks, 0));
+
+        // Moving further than the drag threshold cancels the long press.
+        cx.simulate_mouse_down(start, MouseButton::Left, Modifiers::default());
+        cx.simulate_mouse_move(
+            point(px(15.), px(10.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        advance(cx, 600);
+        view.update(cx, |view, _| assert_eq!(view.long_presses, 1));
+        cx.simulate_mouse_up(start, MouseButton::Left, Modifiers::default());
+        view.update(cx, |view, _| assert_eq!(view.clicks, 1));
+
+        // Releasing the button before the duration cancels it too.
+        cx.simulate_mouse_down(start, MouseButton::Left, Modifiers::default());
+        advance(cx, 300);
+        cx.simulate_mouse_up(start, MouseButton::Left, Modifiers::default());
+        advance(cx, 300);
+        view.update(cx, |view, _| {
+            assert_eq!(view.long_presses, 1);
+            assert_eq!(view.clicks, 2);
+        });
+    }
+
+    struct InteractionView {
+        state: InteractionState,
+    }
+
+    impl Render for InteractionView {
+        fn render(&mut self, _: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            // Keep the element away from the initial mouse position at the window's origin.
+            div()
+                .size_full()
+                .pt(px(50.))
+                .pl(px(50.))
+                .child(div().w(px(100.)).h(px(100.)).track_interaction(&self.state))
+        }
+    }
+
+    #[gpui::test]
+    fn test_track_interaction(cx: &mut TestAppContext) {
+        let state = InteractionState::new();
+        let (view, cx) = cx.add_window_view(|_| InteractionView {
+            state: state.clone(),
+        });
+        let notifications = Rc::new(Cell::new(0));
+        cx.update(|cx| {
+            let notifications = notifications.clone();
+            cx.observe(&view, move |_, _| {
+                notifications.set(notifications.get() + 1)
+            })
+            .detach();
+        });
+        let inside = point(px(60.), px(60.));
+
+        cx.simulate_mouse_move(inside, None, Modifiers::default());
+        assert!(state.is_hovered());
+        assert_eq!(notifications.get(), 1);
+
+        // Moving within the element doesn't change its state.
+        cx.simulate_mouse_move(point(px(70.), px(70.)), None, Modifiers::default());
+        assert_eq!(notifications.get(), 1);
+
+        cx.simulate_mouse_down(inside, MouseButton::Left, Modifiers::default());
+        assert!(state.is_active());
+        assert_eq!(notifications.get(), 2);
+
+        cx.simulate_mouse_up(inside, MouseButton::Left, Modifiers::default());
+        assert!(!state.is_active());
+        assert_eq!(notifications.get(), 3);
+
+        cx.simulate_mouse_move(point(px(10.), px(10.)), None, Modifiers::default());
+        assert!(!state.is_hovered());
+        assert_eq!(notifications.get(), 4);
+    }
+
+    struct ScrollbarView {
+        scroll_handle: ScrollHandle,
+    }
+
+    impl Render for ScrollbarView {
+        fn render(&mut self, _: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            div()
+                .id("scroll")
+                .w(px(100.))
+                .h(px(100.))
+                .overflow_y_scroll()
+                .track_scroll(&self.scroll_handle)
+                .scrollbar_style(ScrollbarStyle {
+                    thickness: px(10.),
+                    inset: px(0.),
+                    ..Default::default()
+                })
+                .child(div().w_full().h(px(400.)))
+        }
+    }
+
+    #[gpui::test]
+    fn test_drag_scrollbar_thumb(cx: &mut TestAppContext) {
+        let scroll_handle = ScrollHandle::new();
+        let (_, cx) = cx.add_window_view(|_| ScrollbarView {
+            scroll_handle: scroll_handle.clone(),
+        });
+
+        // The thumb is painted once the element is hovered. With 300px of overflow in a
+        // 100px track it is 25px long, so each pixel it moves scrolls the content by 4px.
+        cx.simulate_mouse_move(point(px(50.), px(10.)), None, Modifiers::default());
+        cx.update(|cx| cx.draw());
+        cx.simulate_mouse_down(
+            point(px(95.), px(10.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.update(|cx| cx.draw());
+
+        cx.simulate_mouse_move(
+            point(px(95.), px(35.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        assert_eq!(scroll_handle.offset().y, px(-100.));
+
+        // Dragging past the end of the track is clamped to the maximum scroll offset.
+        cx.simulate_mouse_move(
+            point(px(95.), px(200.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        assert_eq!(scroll_handle.offset().y, px(-300.));
+
+        // Releasing the thumb ends the drag.
+        cx.simulate_mouse_up(
+            point(px(95.), px(200.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.update(|cx| cx.draw());
+        cx.simulate_mouse_move(
+            point(px(95.), px(35.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        assert_eq!(scroll_handle.offset().y, px(-300.));
+    }
+
+    #[gpui::test]
+    fn test_on_layout(cx: &mut TestAppContext) {
+        struct LayoutView {
+            bounds: Rc<Cell<Option<Bounds<Pixels>>>>,
+        }
+
+        impl Render for LayoutView {
+            fn render(&mut self, _: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+                let bounds = self.bounds.clone();
+                div().child(
+                    div()
+                        .w(px(30.))
+                        .h(px(20.))
+                        .on_layout(move |element_bounds, _| bounds.set(Some(element_bounds))),
+                )
+            }
+        }
+
+        let bounds = Rc::new(Cell::new(None));
+        cx.add_window_view(|_| LayoutView {
+            bounds: bounds.clone(),
+        });
+        assert_eq!(
+            bounds.get().map(|bounds| bounds.size),
+            Some(size(px(30.), px(20.)))
+        );
+    }
 }
@@ -33,6 +33,21 @@ struct Hsla {
     l: f32,
     a: f32,
 }
+struct ColorStop {
+    color: Hsla,
+    percentage: f32,
+}
+struct Background {
+    // 0 = solid, 1 = linear gradient, 2 = radial gradient
+    tag: u32,
+    angle: f32,
+    center_x: f32,
+    center_y: f32,
+    stop_count: u32,
+    solid: Hsla,
+    colors: array<ColorStop, 4>,
+    pad: u32,
+}
 
 struct AtlasTextureId {
     index: u32,
@@ -189,6 +204,69 @@ fn quad_sdf(point: vec2<f32>, bounds: Bounds, corner_radii: Corners) -> f32 {
         corner_radius;
 }
 
+fn fill_color(background: Background, position: vec2<f32>, bounds: Bounds, solid_color: vec4<f32>) -> vec4<f32> {
+    if (background.tag == 0u) {
+        return solid_color;
+    }
+
+    var t = 0.0;
+    if (background.tag == 1u) {
+        // As in CSS, 0deg points up and the gradient line is just long enough for
+        // the first and last stops to reach the corners of the bounds.
+        let angle = radians(background.angle);
+        let direction = vec2<f32>(sin(angle), -cos(angle));
+        let gradient_length = abs(bounds.size.x * direction.x) + abs(bounds.size.y * direction.y);
+        let center_to_point = position - (bounds.origin + bounds.size / 2.0);
+        t = dot(center_to_point, direction) / max(gradient_length, 0.0001) + 0.5;
+    } else {
+        let center = bounds.origin + bounds.size * vec2<f32>(background.center_x, background.center_y);
+        let farthest_corner = max(center - bounds.origin, bounds.origin + bounds.size - center);
+        t = distance(position, center) / max(length(farthest_corner), 0.0001);
+    }
+
+    // Blend each stop over the previous ones, so that only the segment containing
+    // `t` is partially mixed while the segments before it are fully applied.
+    var stops = background.colors;
+    var color = hsla_to_rgba(stops[0].color);
+    for (var i = 1u; i < background.stop_count; i += 1u) {
+        let previous_stop = stops[i - 1u];
+        let stop = stops[i];
+        let range = stop.percentage - previous_stop.percentage;
+        var progress = step(stop.percentage, t);
+        if (range > 0.0) {
+            progress = saturate((t - previous_stop.percentage) / range);
+        }
+        color = mix(color, hsla_to_rgba(stop.color), progress);
+    }
+    return color;
+}
+
+fn blurred_rect_alpha(position: vec2<f32>, bounds: Bounds, corner_radii: Corners, blur_radius: f32) -> f32 {
+    let half_size = bounds.size / 2.0;
+    let center = bounds.origin + half_size;
+    let center_to_point = position - center;
+
+    let corner_radius = pick_corner_radius(center_to_point, corner_radii);
+
+    // The signal is only non-zero in a limited range, so don't waste samples
+    let low = center_to_point.y - half_size.y;
+    let high = center_to_point.y + half_size.y;
+    let start = clamp(-3.0 * blur_radius, low, high);
+    let end = clamp(3.0 * blur_radius, low, high);
+
+    // Accumulate samples (we can get away with surprisingly few samples)
+    let step = (end - start) / 4.0;
+    var y = start + step * 0.5;
+    var alpha = 0.0;
+    for (var i = 0; i < 4; i += 1) {
+        let blur = blur_along_x(center_to_point.x, center_to_point.y - y,
+            blur_radius, corner_radius, half_size);
+        alpha +=  blur * gaussian(y, blur_radius) * step;
+        y += step;
+    }
+    return alpha;
+}
+
 // Abstract away the final color transformation based on the
 // target alpha compositing mode.
 fn blend_color(color: vec4<f32>, alpha_factor: f32) -> vec4<f32> {
@@ -204,7 +282,7 @@ struct Quad {
     pad: u32,
     bounds: Bounds,
     content_mask: Bounds,
-    background: Hsla,
+    background: Background,
     border_color: Hsla,
     corner_radii: Corners,
     border_widths: Edges,
@@ -227,7 +305,7 @@ fn vs_quad(@builtin(vertex_index) vertex_id: u32, @builtin(instance_index) insta
 
     var out = QuadVarying();
     out.position = to_device_position(unit_vertex, quad.bounds);
-    out.background_color = hsla_to_rgba(quad.background);
+    out.background_color = hsla_to_rgba(quad.background.solid);
     out.border_color = hsla_to_rgba(quad.border_color);
     out.quad_id = instance_id;
     out.clip_distances = distance_from_clip_rect(unit_vertex, quad.bounds, quad.content_mask);
@@ -242,13 +320,15 @@ fn fs_quad(input: QuadVarying) -> @location(0) vec4<f32> {
     }
 
     let quad = b_quads[input.quad_id];
+    let background_color = fill_color(quad.background, input.position.xy, quad.bounds, input.background_color);
+
     // Fast path when the quad is not rounded and doesn't have any border.
     if (quad.corner_radii.top_left == 0.0 && quad.corner_radii.bottom_left == 0.0 &&
         quad.corner_radii.top_right == 0.0 &&
         quad.corner_radii.bottom_right == 0.0 && quad.border_widths.top == 0.0 &&
         quad.border_widths.left == 0.0 && quad.border_widths.right == 0.0 &&
         quad.border_widths.bottom == 0.0) {
-        return blend_color(input.background_color, 1.0);
+        return blend_color(background_color, 1.0);
     }
 
     let half_size = quad.bounds.size / 2.0;
@@ -277,13 +357,13 @@ fn fs_quad(input: QuadVarying) -> @location(0) vec4<f32> {
         border_width = vertical_border;
     }
 
-    var color = input.background_color;
+    var color = background_color;
     if (border_width > 0.0) {
         let inset_distance = distance + border_width;
         // Blend the border on top of the background and then linearly interpolate
         // between the two as we slide inside the background.
-        let blended_border = over(input.background_color, input.border_color);
-        color = mix(blended_border, input.background_color,
+        let blended_border = over(background_color, input.border_color);
+        color = mix(blended_border, background_color,
                     saturate(0.5 - inset_distance));
     }
 
@@ -299,6 +379,9 @@ struct Shadow {
     corner_radii: Corners,
     content_mask: Bounds,
     color: Hsla,
+    inset: u32,
+    pad: u32,
+    inset_bounds: Bounds,
 }
 var<storage, read> b_shadows: array<Shadow>;
 
@@ -315,7 +398,11 @@ fn vs_shadow(@builtin(vertex_index) vertex_id: u32, @builtin(instance_index) ins
     let unit_vertex = vec2<f32>(f32(vertex_id & 1u), 0.5 * f32(vertex_id & 2u));
     var shadow = b_shadows[instance_id];
 
-    let margin = 3.0 * shadow.blur_radius;
+    // Inset shadows are drawn within the element, so they don't need a margin.
+    var margin = 3.0 * shadow.blur_radius;
+    if ((shadow.inset & 0xFFu) != 0u) {
+        margin = 0.0;
+    }
     // Set the bounds of the shadow and adjust its size based on the shadow's
     // spread radius to achieve the spreading effect
     shadow.bounds.origin -= vec2<f32>(margin);
@@ -337,29 +424,15 @@ fn fs_shadow(input: ShadowVarying) -> @location(0) vec4<f32> {
     }
 
     let shadow = b_shadows[input.shadow_id];
-    let half_size = shadow.bounds.size / 2.0;
-    let center = shadow.bounds.origin + half_size;
-    let center_to_point = input.position.xy - center;
-
-    let corner_radius = pick_corner_radius(center_to_point, shadow.corner_radii);
-
-    // The signal is only non-zero in a limited range, so don't waste samples
-    let low = center_to_point.y - half_size.y;
-    let high = center_to_point.y + half_size.y;
-    let start = clamp(-3.0 * shadow.blur_radius, low, high);
-    let end = clamp(3.0 * shadow.blur_radius, low, high);
-
-    // Accumulate samples (we can get away with surprisingly few samples)
-    let step = (end - start) / 4.0;
-    var y = start + step * 0.5;
-    var alpha = 0.0;
-    for (var i = 0; i < 4; i += 1) {
-        let blur = blur_along_x(center_to_point.x, center_to_point.y - y,
-            shadow.blur_radius, corner_radius, half_size);
-        alpha +=  blur * gaussian(y, shadow.blur_radius) * step;
-        y += step;
+    if ((shadow.inset & 0xFFu) != 0u) {
+        // Inset shadows darken everything inside the element except the blurred
+        // area covered by the (offset and shrunk) inset bounds.
+        let alpha = 1.0 - blurred_rect_alpha(input.position.xy, shadow.inset_bounds, shadow.corner_radii, shadow.blur_radius);
+        let distance = quad_sdf(input.position.xy, shadow.bounds, shadow.corner_radii);
+        return blend_color(input.color, alpha * saturate(0.5 - distance));
     }
 
+    let alpha = blurred_rect_alpha(input.position.xy, shadow.bounds, shadow.corner_radii, shadow.blur_radius);
     return blend_color(input.color, alpha);
 }
 
@@ -548,7 +621,9 @@ fn fs_mono_sprite(input: MonoSpriteVarying) -> @location(0) vec4<f32> {
 
 struct PolychromeSprite {
     order: u32,
+    pad: u32,
     grayscale: u32,
+    opacity: f32,
     bounds: Bounds,
     content_mask: Bounds,
     corner_radii: Corners,
@@ -592,7 +667,7 @@ fn fs_poly_sprite(input: PolySpriteVarying) -> @location(0) vec4<f32> {
         let grayscale = dot(color.rgb, GRAYSCALE_FACTORS);
         color = vec4<f32>(vec3<f32>(grayscale), sample.a);
     }
-    return blend_color(color, saturate(0.5 - distance));
+    return blend_color(color, sprite.opacity * saturate(0.5 - distance));
 }
 
 // --- surfaces --- //
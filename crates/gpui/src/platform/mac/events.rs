@@ -1,8 +1,8 @@
 use crate::{
     platform::mac::NSStringExt, point, px, KeyDownEvent, KeyUpEvent, Keystroke, Modifiers,
     ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseExitEvent, MouseMoveEvent,
-    MouseUpEvent, NavigationDirection, Pixels, PlatformInput, ScrollDelta, ScrollWheelEvent,
-    TouchPhase,
+    MouseUpEvent, NavigationDirection, PinchEvent, Pixels, PlatformInput, ScrollDelta,
+    ScrollWheelEvent, TouchPhase,
 };
 use cocoa::{
     appkit::{NSEvent, NSEventModifierFlags, NSEventPhase, NSEventType},
@@ -190,6 +190,26 @@ impl PlatformInput {
                     modifiers: read_modifiers(native_event),
                 })
             }),
+            NSEventType::NSEventTypeMagnify => window_height.map(|window_height| {
+                let phase = match native_event.phase() {
+                    NSEventPhase::NSEventPhaseMayBegin | NSEventPhase::NSEventPhaseBegan => {
+                        TouchPhase::Started
+                    }
+                    NSEventPhase::NSEventPhaseEnded => TouchPhase::Ended,
+                    _ => TouchPhase::Moved,
+                };
+                let magnification: f64 = msg_send![native_event, magnification];
+
+                Self::Pinch(PinchEvent {
+                    position: point(
+                        px(native_event.locationInWindow().x as f32),
+                        window_height - px(native_event.locationInWindow().y as f32),
+                    ),
+                    delta: magnification as f32,
+                    touch_phase: phase,
+                    modifiers: read_modifiers(native_event),
+                })
+            }),
             NSEventType::NSLeftMouseDragged
             | NSEventType::NSRightMouseDragged
             | NSEventType::NSOtherMouseDragged => {
@@ -21,6 +21,10 @@ float2 erf(float2 x);
 float blur_along_x(float x, float y, float sigma, float corner,
                    float2 half_size);
 float4 over(float4 below, float4 above);
+float blurred_rect_alpha(float2 position, Bounds_ScaledPixels bounds,
+                         Corners_ScaledPixels corner_radii, float blur_radius);
+float4 fill_color(Background background, float2 position,
+                  Bounds_ScaledPixels bounds, float4 solid_color);
 
 struct QuadVertexOutput {
   float4 position [[position]];
@@ -51,7 +55,7 @@ vertex QuadVertexOutput quad_vertex(uint unit_vertex_id [[vertex_id]],
       to_device_position(unit_vertex, quad.bounds, viewport_size);
   float4 clip_distance = distance_from_clip_rect(unit_vertex, quad.bounds,
                                                  quad.content_mask.bounds);
-  float4 background_color = hsla_to_rgba(quad.background);
+  float4 background_color = hsla_to_rgba(quad.background.solid);
   float4 border_color = hsla_to_rgba(quad.border_color);
   return QuadVertexOutput{
       device_position,
@@ -65,6 +69,8 @@ fragment float4 quad_fragment(QuadFragmentInput input [[stage_in]],
                               constant Quad *quads
                               [[buffer(QuadInputIndex_Quads)]]) {
   Quad quad = quads[input.quad_id];
+  float4 background_color = fill_color(quad.background, input.position.xy,
+                                       quad.bounds, input.background_color);
 
   // Fast path when the quad is not rounded and doesn't have any border.
   if (quad.corner_radii.top_left == 0. && quad.corner_radii.bottom_left == 0. &&
@@ -72,7 +78,7 @@ fragment float4 quad_fragment(QuadFragmentInput input [[stage_in]],
       quad.corner_radii.bottom_right == 0. && quad.border_widths.top == 0. &&
       quad.border_widths.left == 0. && quad.border_widths.right == 0. &&
       quad.border_widths.bottom == 0.) {
-    return input.background_color;
+    return background_color;
   }
 
   float2 half_size =
@@ -120,13 +126,13 @@ fragment float4 quad_fragment(QuadFragmentInput input [[stage_in]],
 
   float4 color;
   if (border_width == 0.) {
-    color = input.background_color;
+    color = background_color;
   } else {
     float inset_distance = distance + border_width;
     // Blend the border on top of the background and then linearly interpolate
     // between the two as we slide inside the background.
-    float4 blended_border = over(input.background_color, input.border_color);
-    color = mix(blended_border, input.background_color,
+    float4 blended_border = over(background_color, input.border_color);
+    color = mix(blended_border, background_color,
                 saturate(0.5 - inset_distance));
   }
 
@@ -155,7 +161,8 @@ vertex ShadowVertexOutput shadow_vertex(
   float2 unit_vertex = unit_vertices[unit_vertex_id];
   Shadow shadow = shadows[shadow_id];
 
-  float margin = 3. * shadow.blur_radius;
+  // Inset shadows are drawn within the element, so they don't need a margin.
+  float margin = shadow.inset ? 0. : 3. * shadow.blur_radius;
   // Set the bounds of the shadow and adjust its size based on the shadow's
   // spread radius to achieve the spreading effect
   Bounds_ScaledPixels bounds = shadow.bounds;
@@ -182,43 +189,19 @@ fragment float4 shadow_fragment(ShadowFragmentInput input [[stage_in]],
                                 [[buffer(ShadowInputIndex_Shadows)]]) {
   Shadow shadow = shadows[input.shadow_id];
 
-  float2 origin = float2(shadow.bounds.origin.x, shadow.bounds.origin.y);
-  float2 size = float2(shadow.bounds.size.width, shadow.bounds.size.height);
-  float2 half_size = size / 2.;
-  float2 center = origin + half_size;
-  float2 point = input.position.xy - center;
-  float corner_radius;
-  if (point.x < 0.) {
-    if (point.y < 0.) {
-      corner_radius = shadow.corner_radii.top_left;
-    } else {
-      corner_radius = shadow.corner_radii.bottom_left;
-    }
-  } else {
-    if (point.y < 0.) {
-      corner_radius = shadow.corner_radii.top_right;
-    } else {
-      corner_radius = shadow.corner_radii.bottom_right;
-    }
-  }
-
-  // The signal is only non-zero in a limited range, so don't waste samples
-  float low = point.y - half_size.y;
-  float high = point.y + half_size.y;
-  float start = clamp(-3. * shadow.blur_radius, low, high);
-  float end = clamp(3. * shadow.blur_radius, low, high);
-
-  // Accumulate samples (we can get away with surprisingly few samples)
-  float step = (end - start) / 4.;
-  float y = start + step * 0.5;
-  float alpha = 0.;
-  for (int i = 0; i < 4; i++) {
-    alpha += blur_along_x(point.x, point.y - y, shadow.blur_radius,
-                          corner_radius, half_size) *
-             gaussian(y, shadow.blur_radius) * step;
-    y += step;
+  if (shadow.inset) {
+    // Inset shadows darken everything inside the element except the blurred
+    // area covered by the (offset and shrunk) inset bounds.
+    float alpha = 1. - blurred_rect_alpha(input.position.xy, shadow.inset_bounds,
+                                          shadow.corner_radii,
+                                          shadow.blur_radius);
+    float distance =
+        quad_sdf(input.position.xy, shadow.bounds, shadow.corner_radii);
+    return input.color * float4(1., 1., 1., alpha * saturate(0.5 - distance));
   }
 
+  float alpha = blurred_rect_alpha(input.position.xy, shadow.bounds,
+                                   shadow.corner_radii, shadow.blur_radius);
   return input.color * float4(1., 1., 1., alpha);
 }
 
@@ -385,7 +368,7 @@ fragment float4 polychrome_sprite_fragment(
     color.g = grayscale;
     color.b = grayscale;
   }
-  color.a *= saturate(0.5 - distance);
+  color.a *= sprite.opacity * saturate(0.5 - distance);
   return color;
 }
 
@@ -683,6 +666,88 @@ float4 distance_from_clip_rect(float2 unit_vertex, Bounds_ScaledPixels bounds,
                 clip_bounds.origin.y + clip_bounds.size.height - position.y);
 }
 
+float4 fill_color(Background background, float2 position,
+                  Bounds_ScaledPixels bounds, float4 solid_color) {
+  if (background.tag == BackgroundTag_Solid) {
+    return solid_color;
+  }
+
+  float2 origin = float2(bounds.origin.x, bounds.origin.y);
+  float2 size = float2(bounds.size.width, bounds.size.height);
+  float t;
+  if (background.tag == BackgroundTag_LinearGradient) {
+    // As in CSS, 0deg points up and the gradient line is just long enough for
+    // the first and last stops to reach the corners of the bounds.
+    float angle = background.angle * M_PI_F / 180.;
+    float2 direction = float2(sin(angle), -cos(angle));
+    float gradient_length =
+        fabs(size.x * direction.x) + fabs(size.y * direction.y);
+    float2 center_to_point = position - (origin + size / 2.);
+    t = dot(center_to_point, direction) / max(gradient_length, 0.0001) + 0.5;
+  } else {
+    float2 center =
+        origin + size * float2(background.center_x, background.center_y);
+    float2 farthest_corner = max(center - origin, origin + size - center);
+    t = distance(position, center) / max(length(farthest_corner), 0.0001);
+  }
+
+  // Blend each stop over the previous ones, so that only the segment containing
+  // `t` is partially mixed while the segments before it are fully applied.
+  float4 color = hsla_to_rgba(background.colors[0].color);
+  for (uint i = 1; i < background.stop_count; i++) {
+    ColorStop previous_stop = background.colors[i - 1];
+    ColorStop stop = background.colors[i];
+    float range = stop.percentage - previous_stop.percentage;
+    float progress = range > 0.
+                         ? saturate((t - previous_stop.percentage) / range)
+                         : step(stop.percentage, t);
+    color = mix(color, hsla_to_rgba(stop.color), progress);
+  }
+  return color;
+}
+
+float blurred_rect_alpha(float2 position, Bounds_ScaledPixels bounds,
+                         Corners_ScaledPixels corner_radii, float blur_radius) {
+  float2 origin = float2(bounds.origin.x, bounds.origin.y);
+  float2 size = float2(bounds.size.width, bounds.size.height);
+  float2 half_size = size / 2.;
+  float2 center = origin + half_size;
+  float2 point = position - center;
+  float corner_radius;
+  if (point.x < 0.) {
+    if (point.y < 0.) {
+      corner_radius = corner_radii.top_left;
+    } else {
+      corner_radius = corner_radii.bottom_left;
+    }
+  } else {
+    if (point.y < 0.) {
+      corner_radius = corner_radii.top_right;
+    } else {
+      corner_radius = corner_radii.bottom_right;
+    }
+  }
+
+  // The signal is only non-zero in a limited range, so don't waste samples
+  float low = point.y - half_size.y;
+  float high = point.y + half_size.y;
+  float start = clamp(-3. * blur_radius, low, high);
+  float end = clamp(3. * blur_radius, low, high);
+
+  // Accumulate samples (we can get away with surprisingly few samples)
+  float step = (end - start) / 4.;
+  float y = start + step * 0.5;
+  float alpha = 0.;
+  for (int i = 0; i < 4; i++) {
+    alpha += blur_along_x(point.x, point.y - y, blur_radius,
+                          corner_radius, half_size) *
+             gaussian(y, blur_radius) * step;
+    y += step;
+  }
+
+  return alpha;
+}
+
 float4 over(float4 below, float4 above) {
   float4 result;
   float alpha = above.a + below.a * (1.0 - above.a);
@@ -2,8 +2,8 @@
 #![cfg_attr(windows, allow(dead_code))]
 
 use crate::{
-    bounds_tree::BoundsTree, point, AtlasTextureId, AtlasTile, Bounds, ContentMask, Corners, Edges,
-    Hsla, Pixels, Point, Radians, ScaledPixels, Size,
+    bounds_tree::BoundsTree, point, AtlasTextureId, AtlasTile, Background, Bounds, ContentMask,
+    Corners, Edges, Hsla, Pixels, Point, Radians, ScaledPixels, Size,
 };
 use std::{fmt::Debug, iter::Peekable, ops::Range, slice};
 
@@ -421,12 +421,15 @@ pub(crate) struct Quad {
     pub pad: u32, // align to 8 bytes
     pub bounds: Bounds<ScaledPixels>,
     pub content_mask: ContentMask<ScaledPixels>,
-    pub background: Hsla,
+    pub background: Background,
     pub border_color: Hsla,
     pub corner_radii: Corners<ScaledPixels>,
     pub border_widths: Edges<ScaledPixels>,
 }
 
+// The shaders read quads from an array whose stride is a multiple of 8 bytes.
+const _: () = assert!(std::mem::size_of::<Quad>() % 8 == 0);
+
 impl Ord for Quad {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.order.cmp(&other.order)
@@ -484,6 +487,10 @@ pub(crate) struct Shadow {
     pub corner_radii: Corners<ScaledPixels>,
     pub content_mask: ContentMask<ScaledPixels>,
     pub color: Hsla,
+    pub inset: bool,
+    pub pad: u32, // align to 8 bytes
+    /// For inset shadows, the area left unshaded before blurring.
+    pub inset_bounds: Bounds<ScaledPixels>,
 }
 
 impl Ord for Shadow {
@@ -640,17 +647,33 @@ impl From<MonochromeSprite> for Primitive {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 #[repr(C)]
 pub(crate) struct PolychromeSprite {
     pub order: DrawOrder,
+    pub pad: u32, // align to 8 bytes
     pub grayscale: bool,
+    pub opacity: f32,
     pub bounds: Bounds<ScaledPixels>,
     pub content_mask: ContentMask<ScaledPixels>,
     pub corner_radii: Corners<ScaledPixels>,
     pub tile: AtlasTile,
 }
 
+impl PartialEq for PolychromeSprite {
+    fn eq(&self, other: &Self) -> bool {
+        self.order == other.order
+            && self.grayscale == other.grayscale
+            && self.opacity.to_bits() == other.opacity.to_bits()
+            && self.bounds == other.bounds
+            && self.content_mask == other.content_mask
+            && self.corner_radii == other.corner_radii
+            && self.tile == other.tile
+    }
+}
+
+impl Eq for PolychromeSprite {}
+
 impl Ord for PolychromeSprite {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         match self.order.cmp(&other.order) {
@@ -5,10 +5,10 @@ use std::{
 };
 
 use crate::{
-    black, phi, point, quad, rems, AbsoluteLength, Bounds, ContentMask, Corners, CornersRefinement,
-    CursorStyle, DefiniteLength, Edges, EdgesRefinement, Font, FontFeatures, FontStyle, FontWeight,
-    Hsla, Length, Pixels, Point, PointRefinement, Rgba, SharedString, Size, SizeRefinement, Styled,
-    TextRun, WindowContext,
+    black, phi, point, quad, rems, size, AbsoluteLength, Background, Bounds, ContentMask, Corners,
+    CornersRefinement, CursorStyle, DefiniteLength, Edges, EdgesRefinement, Font, FontFeatures,
+    FontStyle, FontWeight, Hsla, Length, Pixels, Point, PointRefinement, Rgba, SharedString, Size,
+    SizeRefinement, Styled, TextRun, WindowContext,
 };
 use collections::HashSet;
 use refineable::Refineable;
@@ -100,12 +100,27 @@ pub struct Style {
     /// The relative rate at which this item shrinks when it is contracting to fit into space, 1.0 is the default value, and this value must be positive.
     pub flex_shrink: f32,
 
+    // Grid properties
+    /// The number of equally sized columns in this grid container. Tracks with explicit sizes
+    /// are not supported.
+    pub grid_cols: Option<u16>,
+    /// The number of equally sized rows in this grid container. Tracks with explicit sizes
+    /// are not supported.
+    pub grid_rows: Option<u16>,
+    /// Where this item is placed among the columns of its grid container
+    pub grid_column: GridPlacement,
+    /// Where this item is placed among the rows of its grid container
+    pub grid_row: GridPlacement,
+
     /// The fill color of this element
     pub background: Option<Fill>,
 
     /// The border color of this element
     pub border_color: Option<Hsla>,
 
+    /// The line style of this element's border
+    pub border_style: BorderStyle,
+
     /// The radius of the corners of this element
     #[refineable]
     pub corner_radii: Corners<AbsoluteLength>,
@@ -113,6 +128,9 @@ pub struct Style {
     /// Box Shadow of the element
     pub box_shadow: SmallVec<[BoxShadow; 2]>,
 
+    /// The opacity of this element and all of its children, between 0.0 and 1.0
+    pub opacity: Option<f32>,
+
     /// The text style of this element
     pub text: TextStyleRefinement,
 
@@ -145,7 +163,7 @@ pub enum Visibility {
 }
 
 /// The possible values of the box-shadow property
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct BoxShadow {
     /// What color should the shadow have?
     pub color: Hsla,
@@ -155,6 +173,60 @@ pub struct BoxShadow {
     pub blur_radius: Pixels,
     /// How much should the shadow spread?
     pub spread_radius: Pixels,
+    /// Should the shadow be drawn inside the element, above its background?
+    pub inset: bool,
+}
+
+/// The line style used to draw an element's border
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BorderStyle {
+    /// A single solid line
+    #[default]
+    Solid,
+    /// A series of dashes, three times as long as the border is wide
+    Dashed,
+    /// A series of round dots, as wide as the border
+    Dotted,
+}
+
+impl BorderStyle {
+    /// The length of each dash and of the gap that follows it, for a border of the given width.
+    fn dash_pattern(self, width: Pixels) -> Option<(Pixels, Pixels)> {
+        match self {
+            BorderStyle::Solid => None,
+            BorderStyle::Dashed => Some((width * 3., width * 2.)),
+            BorderStyle::Dotted => Some((width, width)),
+        }
+    }
+}
+
+/// Splits a border edge of the given length into dashes, stretching the gaps so that
+/// the pattern begins and ends with a full dash. Yields the offset and length of each dash.
+fn dash_segments(
+    length: Pixels,
+    dash: Pixels,
+    gap: Pixels,
+) -> impl Iterator<Item = (Pixels, Pixels)> {
+    let count = if dash > Pixels::ZERO {
+        ((length + gap) / (dash + gap)).floor().max(1.) as usize
+    } else {
+        0
+    };
+
+    let (first_offset, dash, gap) = match count {
+        0 => (Pixels::ZERO, dash, gap),
+        1 => {
+            let dash = dash.min(length);
+            ((length - dash) / 2., dash, gap)
+        }
+        _ => (
+            Pixels::ZERO,
+            dash,
+            (length - dash * count) / (count - 1) as f32,
+        ),
+    };
+
+    (0..count).map(move |ix| (first_offset + (dash + gap) * ix, dash))
 }
 
 /// How to handle whitespace in text
@@ -167,6 +239,14 @@ pub enum WhiteSpace {
     Nowrap,
 }
 
+/// How to display text that doesn't fit within its element
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TextOverflow {
+    /// Truncate the text and append the given string, usually an ellipsis,
+    /// to the last visible line.
+    Ellipsis(&'static str),
+}
+
 /// The properties that can be used to style text in GPUI
 #[derive(Refineable, Clone, Debug, PartialEq)]
 #[refineable(Debug)]
@@ -203,6 +283,12 @@ pub struct TextStyle {
 
     /// How to handle whitespace in the text
     pub white_space: WhiteSpace,
+
+    /// How to display text that overflows the width of its element
+    pub text_overflow: Option<TextOverflow>,
+
+    /// The maximum number of lines to display, any further lines are omitted
+    pub line_clamp: Option<usize>,
 }
 
 impl Default for TextStyle {
@@ -226,6 +312,8 @@ impl Default for TextStyle {
             underline: None,
             strikethrough: None,
             white_space: WhiteSpace::Normal,
+            text_overflow: None,
+            line_clamp: None,
         }
     }
 }
@@ -426,28 +514,44 @@ impl Style {
 
         let rem_size = cx.rem_size();
 
+        let (inset_shadows, shadows): (SmallVec<[BoxShadow; 2]>, SmallVec<[BoxShadow; 2]>) = self
+            .box_shadow
+            .iter()
+            .cloned()
+            .partition(|shadow| shadow.inset);
         cx.paint_shadows(
             bounds,
             self.corner_radii.to_pixels(bounds.size, rem_size),
-            &self.box_shadow,
+            &shadows,
         );
 
-        let background_color = self.background.as_ref().and_then(Fill::color);
-        if background_color.map_or(false, |color| !color.is_transparent()) {
-            let mut border_color = background_color.unwrap_or_default();
+        let background = self.background.as_ref().map(Background::from);
+        if background.map_or(false, |background| !background.is_transparent()) {
+            let background = background.unwrap_or_default();
+            let mut border_color = background.solid;
             border_color.a = 0.;
             cx.paint_quad(quad(
                 bounds,
                 self.corner_radii.to_pixels(bounds.size, rem_size),
-                background_color.unwrap_or_default(),
+                background,
                 Edges::default(),
                 border_color,
             ));
         }
 
+        if !inset_shadows.is_empty() {
+            cx.paint_shadows(
+                bounds,
+                self.corner_radii.to_pixels(bounds.size, rem_size),
+                &inset_shadows,
+            );
+        }
+
         continuation(cx);
 
-        if self.is_border_visible() {
+        if self.is_border_visible() && self.border_style != BorderStyle::Solid {
+            self.paint_patterned_border(bounds, rem_size, cx);
+        } else if self.is_border_visible() {
             let corner_radii = self.corner_radii.to_pixels(bounds.size, rem_size);
             let border_widths = self.border_widths.to_pixels(rem_size);
             let max_border_width = border_widths.max();
@@ -515,6 +619,123 @@ impl Style {
         }
     }
 
+    fn paint_patterned_border(
+        &self,
+        bounds: Bounds<Pixels>,
+        rem_size: Pixels,
+        cx: &mut WindowContext,
+    ) {
+        let corner_radii = self.corner_radii.to_pixels(bounds.size, rem_size);
+        let border_widths = self.border_widths.to_pixels(rem_size);
+        let border_color = self.border_color.unwrap_or_default();
+
+        // The corners are drawn as solid pieces of a regular border quad, and the
+        // dash pattern is laid along the straight part of each edge between them.
+        let top_left = size(
+            corner_radii.top_left.max(border_widths.left),
+            corner_radii.top_left.max(border_widths.top),
+        );
+        let top_right = size(
+            corner_radii.top_right.max(border_widths.right),
+            corner_radii.top_right.max(border_widths.top),
+        );
+        let bottom_right = size(
+            corner_radii.bottom_right.max(border_widths.right),
+            corner_radii.bottom_right.max(border_widths.bottom),
+        );
+        let bottom_left = size(
+            corner_radii.bottom_left.max(border_widths.left),
+            corner_radii.bottom_left.max(border_widths.bottom),
+        );
+
+        let mut background = border_color;
+        background.a = 0.;
+        let corner_quad = quad(
+            bounds,
+            corner_radii,
+            background,
+            border_widths,
+            border_color,
+        );
+        for corner_bounds in [
+            Bounds::new(bounds.origin, top_left),
+            Bounds::new(
+                bounds.upper_right() - point(top_right.width, Pixels::ZERO),
+                top_right,
+            ),
+            Bounds::new(
+                bounds.lower_right() - point(bottom_right.width, bottom_right.height),
+                bottom_right,
+            ),
+            Bounds::new(
+                bounds.lower_left() - point(Pixels::ZERO, bottom_left.height),
+                bottom_left,
+            ),
+        ] {
+            cx.with_content_mask(
+                Some(ContentMask {
+                    bounds: corner_bounds,
+                }),
+                |cx| cx.paint_quad(corner_quad.clone()),
+            );
+        }
+
+        let edges = [
+            (
+                bounds.origin + point(top_left.width, Pixels::ZERO),
+                bounds.size.width - top_left.width - top_right.width,
+                border_widths.top,
+                false,
+            ),
+            (
+                bounds.lower_left() + point(bottom_left.width, -border_widths.bottom),
+                bounds.size.width - bottom_left.width - bottom_right.width,
+                border_widths.bottom,
+                false,
+            ),
+            (
+                bounds.origin + point(Pixels::ZERO, top_left.height),
+                bounds.size.height - top_left.height - bottom_left.height,
+                border_widths.left,
+                true,
+            ),
+            (
+                bounds.upper_right() + point(-border_widths.right, top_right.height),
+                bounds.size.height - top_right.height - bottom_right.height,
+                border_widths.right,
+                true,
+            ),
+        ];
+        for (origin, length, width, vertical) in edges {
+            let Some((dash, gap)) = self.border_style.dash_pattern(width) else {
+                continue;
+            };
+            if width <= Pixels::ZERO || length <= Pixels::ZERO {
+                continue;
+            }
+
+            for (offset, dash) in dash_segments(length, dash, gap) {
+                let dash_bounds = if vertical {
+                    Bounds::new(origin + point(Pixels::ZERO, offset), size(width, dash))
+                } else {
+                    Bounds::new(origin + point(offset, Pixels::ZERO), size(dash, width))
+                };
+                let corner_radius = if self.border_style == BorderStyle::Dotted {
+                    width / 2.
+                } else {
+                    Pixels::ZERO
+                };
+                cx.paint_quad(quad(
+                    dash_bounds,
+                    corner_radius,
+                    border_color,
+                    Edges::default(),
+                    background,
+                ));
+            }
+        }
+    }
+
     fn is_border_visible(&self) -> bool {
         self.border_color
             .map_or(false, |color| !color.is_transparent())
@@ -553,10 +774,17 @@ impl Default for Style {
             flex_grow: 0.0,
             flex_shrink: 1.0,
             flex_basis: Length::Auto,
+            // Grid
+            grid_cols: None,
+            grid_rows: None,
+            grid_column: GridPlacement::default(),
+            grid_row: GridPlacement::default(),
             background: None,
             border_color: None,
+            border_style: BorderStyle::default(),
             corner_radii: Corners::default(),
             box_shadow: Default::default(),
+            opacity: None,
             text: TextStyleRefinement::default(),
             mouse_cursor: None,
 
@@ -568,6 +796,16 @@ impl Default for Style {
     }
 }
 
+/// The placement of a grid item along one axis of its grid container.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GridPlacement {
+    /// The 1-based grid line this item starts at. Negative values count back from the last
+    /// line, and `None` places the item automatically.
+    pub start: Option<i16>,
+    /// The number of tracks this item spans, or `None` to span a single track.
+    pub span: Option<u16>,
+}
+
 /// The properties that can be applied to an underline.
 #[derive(Refineable, Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
 #[refineable(Debug)]
@@ -598,6 +836,8 @@ pub struct StrikethroughStyle {
 pub enum Fill {
     /// A solid color fill.
     Color(Hsla),
+    /// A linear or radial gradient fill, see [`linear_gradient`] and [`radial_gradient`].
+    Gradient(Background),
 }
 
 impl Fill {
@@ -605,6 +845,7 @@ impl Fill {
     pub fn color(&self) -> Option<Hsla> {
         match self {
             Fill::Color(color) => Some(*color),
+            Fill::Gradient(_) => None,
         }
     }
 }
@@ -627,6 +868,21 @@ impl From<Rgba> for Fill {
     }
 }
 
+impl From<Background> for Fill {
+    fn from(background: Background) -> Self {
+        Self::Gradient(background)
+    }
+}
+
+impl From<&Fill> for Background {
+    fn from(fill: &Fill) -> Self {
+        match fill {
+            Fill::Color(color) => (*color).into(),
+            Fill::Gradient(background) => *background,
+        }
+    }
+}
+
 impl From<TextStyle> for HighlightStyle {
     fn from(other: TextStyle) -> Self {
         Self::from(&other)
@@ -778,10 +1034,50 @@ pub fn combine_highlights(
 
 #[cfg(test)]
 mod tests {
-    use crate::{blue, green, red, yellow};
+    use crate::{
+        self as gpui, blue, color_stop, div, green, linear_gradient, px, red, yellow,
+        BackgroundTag, IntoElement, Render, TestAppContext, ViewContext,
+    };
 
     use super::*;
 
+    struct GradientView;
+
+    impl Render for GradientView {
+        fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+            div().size_full().bg(linear_gradient(
+                90.,
+                [
+                    color_stop(red(), 0.),
+                    color_stop(green(), 0.5),
+                    color_stop(blue(), 1.),
+                ],
+            ))
+        }
+    }
+
+    #[gpui::test]
+    fn test_paint_gradient_background(cx: &mut TestAppContext) {
+        let (_, cx) = cx.add_window_view(|_| GradientView);
+        cx.run_until_parked();
+
+        let quads = cx.update(|cx| cx.window.rendered_frame.scene.quads.clone());
+        let gradient = quads
+            .iter()
+            .map(|quad| quad.background)
+            .find(|background| background.tag == BackgroundTag::LinearGradient)
+            .expect("no gradient quad was painted");
+        assert_eq!(gradient.angle, 90.);
+        assert_eq!(
+            gradient.color_stops(),
+            &[
+                color_stop(red(), 0.),
+                color_stop(green(), 0.5),
+                color_stop(blue(), 1.),
+            ]
+        );
+    }
+
     #[test]
     fn test_combine_highlights() {
         assert_eq!(
@@ -870,4 +1166,23 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_dash_segments() {
+        // The gaps stretch so that the pattern begins and ends with a dash.
+        assert_eq!(
+            dash_segments(px(10.), px(3.), px(2.)).collect::<Vec<_>>(),
+            [(px(0.), px(3.)), (px(7.), px(3.))]
+        );
+
+        // A single dash is centered along a short edge.
+        assert_eq!(
+            dash_segments(px(4.), px(3.), px(2.)).collect::<Vec<_>>(),
+            [(px(0.5), px(3.))]
+        );
+        assert_eq!(
+            dash_segments(px(2.), px(3.), px(2.)).collect::<Vec<_>>(),
+            [(px(0.), px(2.))]
+        );
+    }
 }
@@ -1,8 +1,8 @@
 use crate::TextStyleRefinement;
 use crate::{
-    self as gpui, px, relative, rems, AbsoluteLength, AlignItems, CursorStyle, DefiniteLength,
-    Fill, FlexDirection, FlexWrap, Font, FontStyle, FontWeight, Hsla, JustifyContent, Length,
-    SharedString, StyleRefinement, WhiteSpace,
+    self as gpui, px, relative, rems, AbsoluteLength, AlignItems, BorderStyle, CursorStyle,
+    DefiniteLength, Fill, FlexDirection, FlexWrap, Font, FontStyle, FontWeight, Hsla,
+    JustifyContent, Length, SharedString, StyleRefinement, TextOverflow, WhiteSpace,
 };
 pub use gpui_macros::{
     box_shadow_style_methods, cursor_style_methods, margin_style_methods, overflow_style_methods,
@@ -39,6 +39,86 @@ pub trait Styled: Sized {
         self
     }
 
+    /// Sets the display type of the element to `grid`.
+    /// [Docs](https://tailwindcss.com/docs/display)
+    fn grid(mut self) -> Self {
+        self.style().display = Some(Display::Grid);
+        self
+    }
+
+    /// Sets the number of equally sized columns in a grid container.
+    /// Only equally sized tracks are supported, so explicit track sizes such as
+    /// `grid-template-columns: 200px 1fr` have no equivalent yet.
+    /// [Docs](https://tailwindcss.com/docs/grid-template-columns)
+    fn grid_cols(mut self, cols: u16) -> Self {
+        self.style().grid_cols = Some(cols);
+        self
+    }
+
+    /// Sets the number of equally sized rows in a grid container.
+    /// Only equally sized tracks are supported, so explicit track sizes such as
+    /// `grid-template-rows: 200px 1fr` have no equivalent yet.
+    /// [Docs](https://tailwindcss.com/docs/grid-template-rows)
+    fn grid_rows(mut self, rows: u16) -> Self {
+        self.style().grid_rows = Some(rows);
+        self
+    }
+
+    /// Sets the 1-based column line at which a grid item starts.
+    /// [Docs](https://tailwindcss.com/docs/grid-column#starting-and-ending-lines)
+    fn col_start(mut self, start: i16) -> Self {
+        self.style()
+            .grid_column
+            .get_or_insert_with(Default::default)
+            .start = Some(start);
+        self
+    }
+
+    /// Sets the number of columns a grid item spans.
+    /// [Docs](https://tailwindcss.com/docs/grid-column#spanning-columns)
+    fn col_span(mut self, span: u16) -> Self {
+        self.style()
+            .grid_column
+            .get_or_insert_with(Default::default)
+            .span = Some(span);
+        self
+    }
+
+    /// Sets the 1-based row line at which a grid item starts.
+    /// [Docs](https://tailwindcss.com/docs/grid-row#starting-and-ending-lines)
+    fn row_start(mut self, start: i16) -> Self {
+        self.style()
+            .grid_row
+            .get_or_insert_with(Default::default)
+            .start = Some(start);
+        self
+    }
+
+    /// Sets the number of rows a grid item spans.
+    /// [Docs](https://tailwindcss.com/docs/grid-row#spanning-rows)
+    fn row_span(mut self, span: u16) -> Self {
+        self.style()
+            .grid_row
+            .get_or_insert_with(Default::default)
+            .span = Some(span);
+        self
+    }
+
+    /// Sets the preferred aspect ratio of the element, as its width divided by its height.
+    /// The ratio is used to size the element when only one of its dimensions is constrained.
+    /// [Docs](https://tailwindcss.com/docs/aspect-ratio)
+    fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.style().aspect_ratio = Some(ratio);
+        self
+    }
+
+    /// Sets the aspect ratio of the element to 1 / 1.
+    /// [Docs](https://tailwindcss.com/docs/aspect-ratio)
+    fn aspect_square(mut self) -> Self {
+        self.style().aspect_ratio = Some(1.);
+        self
+    }
+
     /// Sets the whitespace of the element to `normal`.
     /// [Docs](https://tailwindcss.com/docs/whitespace#normal)
     fn whitespace_normal(mut self) -> Self {
@@ -57,6 +137,31 @@ pub trait Styled: Sized {
         self
     }
 
+    /// Sets the element to truncate overflowing text with an ellipsis (…).
+    /// [Docs](https://tailwindcss.com/docs/text-overflow#ellipsis)
+    fn text_ellipsis(mut self) -> Self {
+        self.text_style()
+            .get_or_insert_with(Default::default)
+            .text_overflow = Some(TextOverflow::Ellipsis("…"));
+        self
+    }
+
+    /// Sets the element to keep its text on a single line, truncating it with an ellipsis (…)
+    /// when it overflows.
+    /// [Docs](https://tailwindcss.com/docs/text-overflow#truncate)
+    fn truncate(self) -> Self {
+        self.overflow_hidden().whitespace_nowrap().text_ellipsis()
+    }
+
+    /// Sets the maximum number of lines of text to display, omitting any lines that follow.
+    /// [Docs](https://tailwindcss.com/docs/line-clamp)
+    fn line_clamp(mut self, lines: usize) -> Self {
+        self.text_style()
+            .get_or_insert_with(Default::default)
+            .line_clamp = Some(lines);
+        self.overflow_hidden()
+    }
+
     /// Sets the flex direction of the element to `column`.
     /// [Docs](https://tailwindcss.com/docs/flex-direction#column)
     fn flex_col(mut self) -> Self {
@@ -313,6 +418,31 @@ pub trait Styled: Sized {
         self
     }
 
+    /// Sets the border of the element to be drawn with dashes.
+    /// [Docs](https://tailwindcss.com/docs/border-style)
+    fn border_dashed(mut self) -> Self {
+        self.style().border_style = Some(BorderStyle::Dashed);
+        self
+    }
+
+    /// Sets the border of the element to be drawn with dots.
+    /// [Docs](https://tailwindcss.com/docs/border-style)
+    fn border_dotted(mut self) -> Self {
+        self.style().border_style = Some(BorderStyle::Dotted);
+        self
+    }
+
+    /// Sets the opacity of the element and all of its children.
+    ///
+    /// The opacity is multiplied into every primitive painted by the element and its children
+    /// rather than applied to the subtree as a whole, so overlapping children blend with each
+    /// other and appear more opaque where they overlap.
+    /// [Docs](https://tailwindcss.com/docs/opacity)
+    fn opacity(mut self, opacity: f32) -> Self {
+        self.style().opacity = Some(opacity);
+        self
+    }
+
     /// Get the text style that has been configured on this element.
     fn text_style(&mut self) -> &mut Option<TextStyleRefinement> {
         let style: &mut StyleRefinement = self.style();
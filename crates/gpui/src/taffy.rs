@@ -1,6 +1,6 @@
 use crate::{
-    AbsoluteLength, Bounds, DefiniteLength, Edges, Length, Pixels, Point, Size, Style,
-    WindowContext,
+    AbsoluteLength, Bounds, DefiniteLength, Edges, GridPlacement, Length, Pixels, Point, Size,
+    Style, WindowContext,
 };
 use collections::{FxHashMap, FxHashSet};
 use smallvec::SmallVec;
@@ -271,7 +271,44 @@ impl ToTaffy<taffy::style::Style> for Style {
             flex_basis: self.flex_basis.to_taffy(rem_size),
             flex_grow: self.flex_grow,
             flex_shrink: self.flex_shrink,
-            ..Default::default() // Ignore grid properties for now
+            grid_template_columns: grid_template(self.grid_cols),
+            grid_template_rows: grid_template(self.grid_rows),
+            grid_column: self.grid_column.to_taffy(rem_size),
+            grid_row: self.grid_row.to_taffy(rem_size),
+            ..Default::default()
+        }
+    }
+}
+
+/// Converts a track count into `repeat(count, minmax(0, 1fr))`, so that all tracks share the
+/// available space equally regardless of their content.
+fn grid_template(count: Option<u16>) -> Vec<taffy::style::TrackSizingFunction> {
+    count
+        .map(|count| {
+            vec![taffy::style::TrackSizingFunction::Repeat(
+                taffy::style::GridTrackRepetition::Count(count),
+                vec![taffy::geometry::MinMax {
+                    min: taffy::style::MinTrackSizingFunction::Fixed(
+                        taffy::style::LengthPercentage::Length(0.),
+                    ),
+                    max: taffy::style::MaxTrackSizingFunction::Fraction(1.),
+                }],
+            )]
+        })
+        .unwrap_or_default()
+}
+
+impl ToTaffy<taffy::geometry::Line<taffy::style::GridPlacement>> for GridPlacement {
+    fn to_taffy(&self, _rem_size: Pixels) -> taffy::geometry::Line<taffy::style::GridPlacement> {
+        taffy::geometry::Line {
+            start: self
+                .start
+                .map_or(taffy::style::GridPlacement::Auto, |line| {
+                    taffy::style::GridPlacement::Line(line.into())
+                }),
+            end: self.span.map_or(taffy::style::GridPlacement::Auto, |span| {
+                taffy::style::GridPlacement::Span(span)
+            }),
         }
     }
 }
@@ -498,3 +535,54 @@ impl From<Size<Pixels>> for Size<AvailableSpace> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as gpui, div, point, px, size, Bounds, InteractiveElement, IntoElement, ParentElement,
+        Pixels, Render, Styled, TestAppContext, ViewContext,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    struct GridView {
+        bounds: Rc<Cell<Option<Bounds<Pixels>>>>,
+    }
+
+    impl Render for GridView {
+        fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+            let bounds = self.bounds.clone();
+            div()
+                .grid()
+                .grid_cols(4)
+                .grid_rows(2)
+                .w(px(400.))
+                .h(px(200.))
+                .child(div())
+                .child(
+                    div()
+                        .col_start(2)
+                        .col_span(2)
+                        .row_start(2)
+                        .on_layout(move |item_bounds, _| bounds.set(Some(item_bounds))),
+                )
+        }
+    }
+
+    #[gpui::test]
+    fn test_grid_placement(cx: &mut TestAppContext) {
+        let bounds = Rc::new(Cell::new(None));
+        cx.add_window_view(|_| GridView {
+            bounds: bounds.clone(),
+        });
+
+        // The item starts at the second of four 100px columns and spans two of them, in the
+        // second of two 100px rows.
+        assert_eq!(
+            bounds.get(),
+            Some(Bounds::new(
+                point(px(100.), px(100.)),
+                size(px(200.), px(100.))
+            ))
+        );
+    }
+}
@@ -1,17 +1,17 @@
 use crate::{
-    hash, point, prelude::*, px, size, transparent_black, Action, AnyDrag, AnyElement, AnyTooltip,
-    AnyView, AppContext, Arena, Asset, AsyncWindowContext, AvailableSpace, Bounds, BoxShadow,
-    Context, Corners, CursorStyle, Decorations, DevicePixels, DispatchActionListener,
-    DispatchNodeId, DispatchTree, DisplayId, Edges, Effect, Entity, EntityId, EventEmitter,
-    FileDropEvent, Flatten, FontId, Global, GlobalElementId, GlyphId, Hsla, ImageData,
-    InputHandler, IsZero, KeyBinding, KeyContext, KeyDownEvent, KeyEvent, KeyMatch, KeymatchResult,
-    Keystroke, KeystrokeEvent, LayoutId, LineLayoutIndex, Model, ModelContext, Modifiers,
-    ModifiersChangedEvent, MonochromeSprite, MouseButton, MouseEvent, MouseMoveEvent, MouseUpEvent,
-    Path, Pixels, PlatformAtlas, PlatformDisplay, PlatformInput, PlatformInputHandler,
-    PlatformWindow, Point, PolychromeSprite, PromptLevel, Quad, Render, RenderGlyphParams,
-    RenderImageParams, RenderSvgParams, ResizeEdge, ScaledPixels, Scene, Shadow, SharedString,
-    Size, StrikethroughStyle, Style, SubscriberSet, Subscription, TaffyLayoutEngine, Task,
-    TextStyle, TextStyleRefinement, TransformationMatrix, Underline, UnderlineStyle, View,
+    hash, hsla, point, prelude::*, px, size, transparent_black, white, Action, AnyDrag, AnyElement,
+    AnyTooltip, AnyView, AppContext, Arena, Asset, AsyncWindowContext, AvailableSpace, Background,
+    Bounds, BoxShadow, Context, Corners, CursorStyle, Decorations, DevicePixels,
+    DispatchActionListener, DispatchNodeId, DispatchTree, DisplayId, Edges, Effect, Entity,
+    EntityId, EventEmitter, FileDropEvent, Flatten, FontId, Global, GlobalElementId, GlyphId, Hsla,
+    ImageData, InputHandler, IsZero, KeyBinding, KeyContext, KeyDownEvent, KeyEvent, KeyMatch,
+    KeymatchResult, Keystroke, KeystrokeEvent, LayoutId, LineLayoutIndex, Model, ModelContext,
+    Modifiers, ModifiersChangedEvent, MonochromeSprite, MouseButton, MouseEvent, MouseMoveEvent,
+    MouseUpEvent, Path, Pixels, PlatformAtlas, PlatformDisplay, PlatformInput,
+    PlatformInputHandler, PlatformWindow, Point, PolychromeSprite, PromptLevel, Quad, Render,
+    RenderGlyphParams, RenderImageParams, RenderSvgParams, ResizeEdge, ScaledPixels, Scene, Shadow,
+    SharedString, Size, StrikethroughStyle, Style, SubscriberSet, Subscription, TaffyLayoutEngine,
+    Task, TextStyle, TextStyleRefinement, TransformationMatrix, Underline, UnderlineStyle, View,
     VisualContext, WeakView, WindowAppearance, WindowBackgroundAppearance, WindowBounds,
     WindowControls, WindowDecorations, WindowOptions, WindowParams, WindowTextSystem,
     SUBPIXEL_VARIANTS,
@@ -321,6 +321,12 @@ pub(crate) struct CursorStyleRequest {
     pub(crate) style: CursorStyle,
 }
 
+#[derive(Clone, Copy)]
+pub(crate) struct TabStop {
+    pub(crate) focus_id: FocusId,
+    pub(crate) tab_index: isize,
+}
+
 /// An identifier for a [Hitbox].
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub struct HitboxId(usize);
@@ -408,6 +414,7 @@ pub(crate) struct Frame {
     pub(crate) input_handlers: Vec<Option<PlatformInputHandler>>,
     pub(crate) tooltip_requests: Vec<Option<TooltipRequest>>,
     pub(crate) cursor_styles: Vec<CursorStyleRequest>,
+    pub(crate) tab_stops: Vec<TabStop>,
     #[cfg(any(test, feature = "test-support"))]
     pub(crate) debug_bounds: FxHashMap<String, Bounds<Pixels>>,
 }
@@ -428,6 +435,7 @@ pub(crate) struct PaintIndex {
     mouse_listeners_index: usize,
     input_handlers_index: usize,
     cursor_styles_index: usize,
+    tab_stops_index: usize,
     accessed_element_states_index: usize,
     line_layout_index: LineLayoutIndex,
 }
@@ -447,6 +455,7 @@ impl Frame {
             input_handlers: Vec::new(),
             tooltip_requests: Vec::new(),
             cursor_styles: Vec::new(),
+            tab_stops: Vec::new(),
 
             #[cfg(any(test, feature = "test-support"))]
             debug_bounds: FxHashMap::default(),
@@ -462,6 +471,7 @@ impl Frame {
         self.input_handlers.clear();
         self.tooltip_requests.clear();
         self.cursor_styles.clear();
+        self.tab_stops.clear();
         self.hitboxes.clear();
         self.deferred_draws.clear();
     }
@@ -521,6 +531,7 @@ pub struct Window {
     pub(crate) text_style_stack: Vec<TextStyleRefinement>,
     pub(crate) element_offset_stack: Vec<Point<Pixels>>,
     pub(crate) content_mask_stack: Vec<ContentMask<Pixels>>,
+    pub(crate) element_opacity: Option<f32>,
     pub(crate) requested_autoscroll: Option<Bounds<Pixels>>,
     pub(crate) rendered_frame: Frame,
     pub(crate) next_frame: Frame,
@@ -554,6 +565,53 @@ pub struct Window {
     pending_modifier: ModifierState,
     pending_input_observers: SubscriberSet<(), AnyObserver>,
     prompt: Option<RenderablePromptHandle>,
+    inspector_enabled: bool,
+    inspector_style: InspectorStyle,
+    inspected_element: Option<InspectedElement>,
+}
+
+/// The element under the mouse while the inspector is enabled, see
+/// [`WindowContext::toggle_inspector`].
+#[derive(Clone)]
+struct InspectedElement {
+    bounds: Bounds<Pixels>,
+    details: Vec<String>,
+}
+
+/// The appearance of the element inspector overlay, see [`WindowContext::set_inspector_style`].
+#[derive(Clone, Debug)]
+pub struct InspectorStyle {
+    /// The font size of the details panel.
+    pub font_size: Pixels,
+    /// The line height of the details panel.
+    pub line_height: Pixels,
+    /// The width of the details panel.
+    pub panel_width: Pixels,
+    /// The padding around the text of the details panel.
+    pub panel_padding: Pixels,
+    /// The background of the details panel.
+    pub panel_background: Hsla,
+    /// The text color of the details panel.
+    pub text_color: Hsla,
+    /// The fill painted over the inspected element.
+    pub highlight_fill: Hsla,
+    /// The outline painted around the inspected element.
+    pub highlight_border: Hsla,
+}
+
+impl Default for InspectorStyle {
+    fn default() -> Self {
+        Self {
+            font_size: px(11.),
+            line_height: px(15.),
+            panel_width: px(420.),
+            panel_padding: px(8.),
+            panel_background: hsla(0., 0., 0.1, 0.9),
+            text_color: white(),
+            highlight_fill: hsla(0.58, 0.9, 0.6, 0.2),
+            highlight_border: hsla(0.58, 0.9, 0.5, 1.),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -821,6 +879,7 @@ impl Window {
             text_style_stack: Vec::new(),
             element_offset_stack: Vec::new(),
             content_mask_stack: Vec::new(),
+            element_opacity: None,
             requested_autoscroll: None,
             rendered_frame: Frame::new(DispatchTree::new(cx.keymap.clone(), cx.actions.clone())),
             next_frame: Frame::new(DispatchTree::new(cx.keymap.clone(), cx.actions.clone())),
@@ -854,6 +913,9 @@ impl Window {
             pending_modifier: ModifierState::default(),
             pending_input_observers: SubscriberSet::new(),
             prompt: None,
+            inspector_enabled: false,
+            inspector_style: InspectorStyle::default(),
+            inspected_element: None,
         })
     }
     fn new_focus_listener(
@@ -975,6 +1037,60 @@ impl<'a> WindowContext<'a> {
         self.refresh();
     }
 
+    /// Move focus to the next element in tab order, wrapping around after the last one. Elements
+    /// with a positive tab index come first in ascending order, followed by elements with a tab
+    /// index of zero in the order they were painted. See [`Self::insert_tab_stop`].
+    pub fn focus_next(&mut self) {
+        let tab_stops = self.tab_order();
+        let next = self
+            .focused_tab_stop(&tab_stops)
+            .map_or(0, |ix| (ix + 1) % tab_stops.len());
+        if let Some(handle) = tab_stops
+            .get(next)
+            .and_then(|id| FocusHandle::for_id(*id, &self.window.focus_handles))
+        {
+            self.focus(&handle);
+        }
+    }
+
+    /// Move focus to the previous element in tab order, wrapping around before the first one.
+    /// See [`Self::focus_next`].
+    pub fn focus_prev(&mut self) {
+        let tab_stops = self.tab_order();
+        let prev = self
+            .focused_tab_stop(&tab_stops)
+            .unwrap_or(0)
+            .checked_sub(1)
+            .unwrap_or(tab_stops.len().saturating_sub(1));
+        if let Some(handle) = tab_stops
+            .get(prev)
+            .and_then(|id| FocusHandle::for_id(*id, &self.window.focus_handles))
+        {
+            self.focus(&handle);
+        }
+    }
+
+    fn tab_order(&self) -> Vec<FocusId> {
+        let mut tab_stops = self
+            .window
+            .rendered_frame
+            .tab_stops
+            .iter()
+            .filter(|tab_stop| tab_stop.tab_index >= 0)
+            .copied()
+            .collect::<Vec<_>>();
+        tab_stops.sort_by_key(|tab_stop| (tab_stop.tab_index == 0, tab_stop.tab_index));
+        tab_stops
+            .into_iter()
+            .map(|tab_stop| tab_stop.focus_id)
+            .collect()
+    }
+
+    fn focused_tab_stop(&self, tab_stops: &[FocusId]) -> Option<usize> {
+        let focus_id = self.window.focus?;
+        tab_stops.iter().position(|id| *id == focus_id)
+    }
+
     /// Remove focus from all elements within this context's window.
     pub fn blur(&mut self) {
         if !self.window.focus_enabled {
@@ -1172,6 +1288,20 @@ impl<'a> WindowContext<'a> {
         RefCell::borrow_mut(&self.window.next_frame_callbacks).push(Box::new(callback));
     }
 
+    /// Schedule another frame to be drawn directly after the current one, so that an element
+    /// can advance an animation from its paint. The view containing the element is notified,
+    /// so this also works inside cached views. Call this on every frame the animation runs.
+    pub fn request_animation_frame(&mut self) {
+        let parent_id = self.parent_view_id();
+        self.on_next_frame(move |cx| {
+            if let Some(parent_id) = parent_id {
+                cx.notify(parent_id)
+            } else {
+                cx.refresh()
+            }
+        });
+    }
+
     /// Spawn the future returned by the given closure on the application thread pool.
     /// The closure is provided a handle to the current window and an `AsyncWindowContext` for
     /// use within your future.
@@ -1514,6 +1644,7 @@ impl<'a> WindowContext<'a> {
     fn draw_roots(&mut self) {
         self.window.draw_phase = DrawPhase::Prepaint;
         self.window.tooltip_bounds.take();
+        self.window.inspected_element.take();
 
         // Layout all root elements.
         let mut root_element = self.window.root_view.as_ref().unwrap().clone().into_any();
@@ -1557,6 +1688,110 @@ impl<'a> WindowContext<'a> {
         } else if let Some(mut tooltip_element) = tooltip_element {
             tooltip_element.paint(self);
         }
+
+        self.paint_inspector();
+    }
+
+    /// Toggle the element inspector for this window. While it is enabled, the element under the
+    /// mouse is highlighted, and a panel describing its computed style, style cascade and
+    /// listeners is painted on top of the window.
+    pub fn toggle_inspector(&mut self) {
+        self.window.inspector_enabled = !self.window.inspector_enabled;
+        self.refresh();
+    }
+
+    /// Returns whether the element inspector is enabled for this window.
+    pub fn inspector_enabled(&self) -> bool {
+        self.window.inspector_enabled
+    }
+
+    /// Set the appearance of the element inspector for this window, e.g. to match the theme.
+    pub fn set_inspector_style(&mut self, style: InspectorStyle) {
+        self.window.inspector_style = style;
+        self.refresh();
+    }
+
+    /// Returns the bounds and description of the element the inspector reported in the last
+    /// frame, if any.
+    #[cfg(any(test, feature = "test-support"))]
+    pub fn inspected_element(&self) -> Option<(Bounds<Pixels>, &[String])> {
+        self.window
+            .inspected_element
+            .as_ref()
+            .map(|inspected| (inspected.bounds, inspected.details.as_slice()))
+    }
+
+    /// Record the element with the given bounds as the one under the mouse. Elements are painted
+    /// from back to front, so the last element recorded during a frame is the topmost one.
+    pub(crate) fn inspect_element(
+        &mut self,
+        bounds: Bounds<Pixels>,
+        details: impl FnOnce() -> Vec<String>,
+    ) {
+        debug_assert_eq!(
+            self.window.draw_phase,
+            DrawPhase::Paint,
+            "this method can only be called during paint"
+        );
+
+        if self.window.inspector_enabled {
+            self.window.inspected_element = Some(InspectedElement {
+                bounds,
+                details: details(),
+            });
+        }
+    }
+
+    fn paint_inspector(&mut self) {
+        let Some(inspected) = self.window.inspected_element.clone() else {
+            return;
+        };
+        let style = self.window.inspector_style.clone();
+
+        self.paint_quad(fill(inspected.bounds, style.highlight_fill));
+        self.paint_quad(outline(inspected.bounds, style.highlight_border));
+
+        // Keep the panel on the opposite side of the window from the inspected element.
+        let viewport_size = self.viewport_size();
+        let panel_x = if inspected.bounds.center().x > viewport_size.width / 2. {
+            Pixels::ZERO
+        } else {
+            viewport_size.width - style.panel_width
+        };
+        let panel_height =
+            style.line_height * inspected.details.len() as f32 + style.panel_padding * 2.;
+        let panel_bounds = Bounds::new(
+            point(panel_x, Pixels::ZERO),
+            size(style.panel_width, panel_height.min(viewport_size.height)),
+        );
+        self.paint_quad(fill(panel_bounds, style.panel_background));
+
+        let text_style = TextStyle {
+            color: style.text_color,
+            ..self.text_style()
+        };
+        self.with_content_mask(
+            Some(ContentMask {
+                bounds: panel_bounds,
+            }),
+            |cx| {
+                for (ix, line) in inspected.details.into_iter().enumerate() {
+                    let run = text_style.to_run(line.len());
+                    let Ok(line) =
+                        cx.text_system()
+                            .shape_line(line.into(), style.font_size, &[run])
+                    else {
+                        continue;
+                    };
+                    let origin = panel_bounds.origin
+                        + point(
+                            style.panel_padding,
+                            style.panel_padding + style.line_height * ix as f32,
+                        );
+                    line.paint(origin, style.line_height, cx).ok();
+                }
+            },
+        );
     }
 
     fn prepaint_tooltip(&mut self) -> Option<AnyElement> {
@@ -1731,6 +1966,7 @@ impl<'a> WindowContext<'a> {
             mouse_listeners_index: self.window.next_frame.mouse_listeners.len(),
             input_handlers_index: self.window.next_frame.input_handlers.len(),
             cursor_styles_index: self.window.next_frame.cursor_styles.len(),
+            tab_stops_index: self.window.next_frame.tab_stops.len(),
             accessed_element_states_index: self.window.next_frame.accessed_element_states.len(),
             line_layout_index: self.window.text_system.layout_index(),
         }
@@ -1745,6 +1981,10 @@ impl<'a> WindowContext<'a> {
                 .iter()
                 .cloned(),
         );
+        window.next_frame.tab_stops.extend_from_slice(
+            &window.rendered_frame.tab_stops
+                [range.start.tab_stops_index..range.end.tab_stops_index],
+        );
         window.next_frame.input_handlers.extend(
             window.rendered_frame.input_handlers
                 [range.start.input_handlers_index..range.end.input_handlers_index]
@@ -1772,6 +2012,21 @@ impl<'a> WindowContext<'a> {
         );
     }
 
+    /// Register the given focus handle as a stop for [`Self::focus_next`] and [`Self::focus_prev`].
+    /// Elements with a negative `tab_index` can be focused but are skipped by tab traversal.
+    /// This method should only be called as part of the paint phase of element drawing.
+    pub fn insert_tab_stop(&mut self, focus_handle: &FocusHandle, tab_index: isize) {
+        debug_assert_eq!(
+            self.window.draw_phase,
+            DrawPhase::Paint,
+            "this method can only be called during paint"
+        );
+        self.window.next_frame.tab_stops.push(TabStop {
+            focus_id: focus_handle.id,
+            tab_index,
+        });
+    }
+
     /// Push a text style onto the stack, and call a function with that style active.
     /// Use [`AppContext::text_style`] to get the current, combined text style. This method
     /// should only be called as part of element drawing.
@@ -1854,6 +2109,34 @@ impl<'a> WindowContext<'a> {
         }
     }
 
+    /// Invoke the given function with the given opacity multiplied into the current element
+    /// opacity, so that everything painted within it is composited at reduced alpha.
+    /// The opacity is applied to each primitive individually; there is no offscreen layer, so
+    /// primitives that overlap within the function are blended with each other.
+    /// This method should only be called during the paint phase of element drawing.
+    pub fn with_element_opacity<R>(
+        &mut self,
+        opacity: Option<f32>,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        debug_assert_eq!(
+            self.window.draw_phase,
+            DrawPhase::Paint,
+            "this method can only be called during paint"
+        );
+
+        let Some(opacity) = opacity else {
+            return f(self);
+        };
+
+        let parent_opacity = self.window.element_opacity;
+        self.window.element_opacity =
+            Some(parent_opacity.map_or(opacity, |parent_opacity| parent_opacity * opacity));
+        let result = f(self);
+        self.window.element_opacity = parent_opacity;
+        result
+    }
+
     /// Updates the global element offset relative to the current offset. This is used to implement
     /// scrolling. This method should only be called during the prepaint phase of element drawing.
     pub fn with_element_offset<R>(
@@ -2073,6 +2356,22 @@ impl<'a> WindowContext<'a> {
             })
     }
 
+    /// The opacity at which primitives are currently being painted, as set by
+    /// [`Self::with_element_opacity`].
+    pub fn element_opacity(&self) -> f32 {
+        debug_assert_eq!(
+            self.window.draw_phase,
+            DrawPhase::Paint,
+            "this method can only be called during paint"
+        );
+        self.window.element_opacity.unwrap_or(1.0).clamp(0., 1.)
+    }
+
+    fn apply_opacity(&self, mut color: Hsla) -> Hsla {
+        color.a *= self.element_opacity();
+        color
+    }
+
     /// Provide elements in the called function with a new namespace in which their identiers must be unique.
     /// This can be used within a custom element to distinguish multiple sets of child elements.
     pub fn with_element_namespace<R>(
@@ -2273,6 +2572,7 @@ impl<'a> WindowContext<'a> {
     }
 
     /// Paint one or more drop shadows into the scene for the next frame at the current z-index.
+    /// Inset shadows are painted within the given bounds instead of around them.
     ///
     /// This method should only be called as part of the paint phase of element drawing.
     pub fn paint_shadows(
@@ -2290,16 +2590,27 @@ impl<'a> WindowContext<'a> {
         let scale_factor = self.scale_factor();
         let content_mask = self.content_mask();
         for shadow in shadows {
-            let mut shadow_bounds = bounds;
-            shadow_bounds.origin += shadow.offset;
-            shadow_bounds.dilate(shadow.spread_radius);
+            let color = self.apply_opacity(shadow.color);
+            let (shadow_bounds, inset_bounds) = if shadow.inset {
+                let mut inset_bounds = bounds.inset(shadow.spread_radius);
+                inset_bounds.origin += shadow.offset;
+                (bounds, inset_bounds)
+            } else {
+                let mut shadow_bounds = bounds;
+                shadow_bounds.origin += shadow.offset;
+                shadow_bounds.dilate(shadow.spread_radius);
+                (shadow_bounds, Bounds::default())
+            };
             self.window.next_frame.scene.insert_primitive(Shadow {
                 order: 0,
                 blur_radius: shadow.blur_radius.scale(scale_factor),
                 bounds: shadow_bounds.scale(scale_factor),
                 content_mask: content_mask.scale(scale_factor),
                 corner_radii: corner_radii.scale(scale_factor),
-                color: shadow.color,
+                color,
+                inset: shadow.inset,
+                pad: 0,
+                inset_bounds: inset_bounds.scale(scale_factor),
             });
         }
     }
@@ -2318,13 +2629,15 @@ impl<'a> WindowContext<'a> {
 
         let scale_factor = self.scale_factor();
         let content_mask = self.content_mask();
+        let background = quad.background.opacity(self.element_opacity());
+        let border_color = self.apply_opacity(quad.border_color);
         self.window.next_frame.scene.insert_primitive(Quad {
             order: 0,
             pad: 0,
             bounds: quad.bounds.scale(scale_factor),
             content_mask: content_mask.scale(scale_factor),
-            background: quad.background,
-            border_color: quad.border_color,
+            background,
+            border_color,
             corner_radii: quad.corner_radii.scale(scale_factor),
             border_widths: quad.border_widths.scale(scale_factor),
         });
@@ -2343,7 +2656,7 @@ impl<'a> WindowContext<'a> {
         let scale_factor = self.scale_factor();
         let content_mask = self.content_mask();
         path.content_mask = content_mask;
-        path.color = color.into();
+        path.color = self.apply_opacity(color.into());
         self.window
             .next_frame
             .scene
@@ -2376,13 +2689,14 @@ impl<'a> WindowContext<'a> {
             size: size(width, height),
         };
         let content_mask = self.content_mask();
+        let color = self.apply_opacity(style.color.unwrap_or_default());
 
         self.window.next_frame.scene.insert_primitive(Underline {
             order: 0,
             pad: 0,
             bounds: bounds.scale(scale_factor),
             content_mask: content_mask.scale(scale_factor),
-            color: style.color.unwrap_or_default(),
+            color,
             thickness: style.thickness.scale(scale_factor),
             wavy: style.wavy,
         });
@@ -2410,6 +2724,7 @@ impl<'a> WindowContext<'a> {
             size: size(width, height),
         };
         let content_mask = self.content_mask();
+        let color = self.apply_opacity(style.color.unwrap_or_default());
 
         self.window.next_frame.scene.insert_primitive(Underline {
             order: 0,
@@ -2417,7 +2732,7 @@ impl<'a> WindowContext<'a> {
             bounds: bounds.scale(scale_factor),
             content_mask: content_mask.scale(scale_factor),
             thickness: style.thickness.scale(scale_factor),
-            color: style.color.unwrap_or_default(),
+            color,
             wavy: false,
         });
     }
@@ -2474,6 +2789,7 @@ impl<'a> WindowContext<'a> {
                 size: tile.bounds.size.map(Into::into),
             };
             let content_mask = self.content_mask().scale(scale_factor);
+            let color = self.apply_opacity(color);
             self.window
                 .next_frame
                 .scene
@@ -2539,13 +2855,16 @@ impl<'a> WindowContext<'a> {
                 size: tile.bounds.size.map(Into::into),
             };
             let content_mask = self.content_mask().scale(scale_factor);
+            let opacity = self.element_opacity();
 
             self.window
                 .next_frame
                 .scene
                 .insert_primitive(PolychromeSprite {
                     order: 0,
+                    pad: 0,
                     grayscale: false,
+                    opacity,
                     bounds,
                     corner_radii: Default::default(),
                     content_mask,
@@ -2594,6 +2913,7 @@ impl<'a> WindowContext<'a> {
             return Ok(());
         };
         let content_mask = self.content_mask().scale(scale_factor);
+        let color = self.apply_opacity(color);
 
         self.window
             .next_frame
@@ -2640,13 +2960,16 @@ impl<'a> WindowContext<'a> {
             .expect("Callback above only returns Some");
         let content_mask = self.content_mask().scale(scale_factor);
         let corner_radii = corner_radii.scale(scale_factor);
+        let opacity = self.element_opacity();
 
         self.window
             .next_frame
             .scene
             .insert_primitive(PolychromeSprite {
                 order: 0,
+                pad: 0,
                 grayscale,
+                opacity,
                 bounds,
                 content_mask,
                 corner_radii,
@@ -3097,6 +3420,11 @@ impl<'a> WindowContext<'a> {
                 self.window.modifiers = scroll_wheel.modifiers;
                 PlatformInput::ScrollWheel(scroll_wheel)
             }
+            PlatformInput::Pinch(pinch) => {
+                self.window.mouse_position = pinch.position;
+                self.window.modifiers = pinch.modifiers;
+                PlatformInput::Pinch(pinch)
+            }
             // Translate dragging and dropping of external files from the operating system
             // to internal drag and drop events.
             PlatformInput::FileDrop(file_drop) => match file_drop {
@@ -4891,8 +5219,8 @@ pub struct PaintQuad {
     pub bounds: Bounds<Pixels>,
     /// The radii of the quad's corners.
     pub corner_radii: Corners<Pixels>,
-    /// The background color of the quad.
-    pub background: Hsla,
+    /// The background of the quad.
+    pub background: Background,
     /// The widths of the quad's borders.
     pub border_widths: Edges<Pixels>,
     /// The color of the quad's borders.
@@ -4924,8 +5252,8 @@ impl PaintQuad {
         }
     }
 
-    /// Sets the background color of the quad.
-    pub fn background(self, background: impl Into<Hsla>) -> Self {
+    /// Sets the background of the quad.
+    pub fn background(self, background: impl Into<Background>) -> Self {
         PaintQuad {
             background: background.into(),
             ..self
@@ -4937,7 +5265,7 @@ impl PaintQuad {
 pub fn quad(
     bounds: Bounds<Pixels>,
     corner_radii: impl Into<Corners<Pixels>>,
-    background: impl Into<Hsla>,
+    background: impl Into<Background>,
     border_widths: impl Into<Edges<Pixels>>,
     border_color: impl Into<Hsla>,
 ) -> PaintQuad {
@@ -4951,7 +5279,7 @@ pub fn quad(
 }
 
 /// Creates a filled quad with the given bounds and background color.
-pub fn fill(bounds: impl Into<Bounds<Pixels>>, background: impl Into<Hsla>) -> PaintQuad {
+pub fn fill(bounds: impl Into<Bounds<Pixels>>, background: impl Into<Background>) -> PaintQuad {
     PaintQuad {
         bounds: bounds.into(),
         corner_radii: (0.).into(),
@@ -4966,7 +5294,7 @@ pub fn outline(bounds: impl Into<Bounds<Pixels>>, border_color: impl Into<Hsla>)
     PaintQuad {
         bounds: bounds.into(),
         corner_radii: (0.).into(),
-        background: transparent_black(),
+        background: transparent_black().into(),
         border_widths: (1.).into(),
         border_color: border_color.into(),
     }
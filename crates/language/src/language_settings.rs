@@ -120,6 +120,8 @@ pub struct LanguageSettings {
     pub code_actions_on_format: HashMap<String, bool>,
     /// Whether to perform linked edits
     pub linked_edits: bool,
+    /// Whether to color brackets by their nesting depth.
+    pub colorize_brackets: bool,
     /// Task configuration for this language.
     pub tasks: LanguageTaskConfig,
 }
@@ -342,6 +344,12 @@ pub struct LanguageSettingsContent {
     ///
     /// Default: true
     pub linked_edits: Option<bool>,
+    /// Whether to color brackets by their nesting depth, using the
+    /// accent colors of the current theme.
+    ///
+    /// Default: false
+    #[serde(default)]
+    pub colorize_brackets: Option<bool>,
     /// Task configuration for this language.
     ///
     /// Default: {}
@@ -418,6 +426,8 @@ pub enum ShowWhitespaceSetting {
     /// - It is adjacent to an edge (start or end)
     /// - It is adjacent to a whitespace (left or right)
     Boundary,
+    /// Draw and highlight whitespaces at the end of lines, and whitespaces in the selected text.
+    Trailing,
 }
 
 /// Controls which formatter should be used when formatting code.
@@ -836,6 +846,7 @@ fn merge_settings(settings: &mut LanguageSettings, src: &LanguageSettingsContent
         src.code_actions_on_format.clone(),
     );
     merge(&mut settings.linked_edits, src.linked_edits);
+    merge(&mut settings.colorize_brackets, src.colorize_brackets);
     merge(&mut settings.tasks, src.tasks.clone());
 
     merge(
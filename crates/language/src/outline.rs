@@ -172,6 +172,7 @@ pub fn render_item<T>(
         underline: None,
         strikethrough: None,
         white_space: WhiteSpace::Normal,
+        ..Default::default()
     };
     let highlights = gpui::combine_highlights(
         custom_highlights,
@@ -101,6 +101,12 @@ pub struct InlineBlameSettings {
     ///
     /// Default: 0
     pub min_column: Option<u32>,
+    /// Whether to show the commit summary next to the author and
+    /// commit time of the inline blame information.
+    ///
+    /// Default: false
+    #[serde(default)]
+    pub show_commit_summary: bool,
 }
 
 const fn true_value() -> bool {
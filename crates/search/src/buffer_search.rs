@@ -123,6 +123,7 @@ impl BufferSearchBar {
             underline: None,
             strikethrough: None,
             white_space: WhiteSpace::Normal,
+            ..Default::default()
         };
 
         EditorElement::new(
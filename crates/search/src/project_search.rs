@@ -1321,6 +1321,7 @@ impl ProjectSearchBar {
             underline: None,
             strikethrough: None,
             white_space: WhiteSpace::Normal,
+            ..Default::default()
         };
 
         EditorElement::new(
@@ -12,14 +12,34 @@ type TabStop = SmallVec<[Range<isize>; 2]>;
 
 impl Snippet {
     pub fn parse(source: &str) -> Result<Self> {
+        Self::parse_with_variables(source, |_| None)
+    }
+
+    /// Parses a snippet, replacing variables such as `$TM_FILENAME` or `${TM_FILENAME:default}`
+    /// with the values returned by `resolve_variable`. Variables that can't be resolved are
+    /// replaced with their default. When they have none, their name is inserted instead, as a
+    /// placeholder that comes after the snippet's numbered tabstops.
+    pub fn parse_with_variables(
+        source: &str,
+        resolve_variable: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self> {
         let mut text = String::with_capacity(source.len());
         let mut tabstops = BTreeMap::new();
-        parse_snippet(source, false, &mut text, &mut tabstops)
-            .context("failed to parse snippet")?;
+        let mut variable_placeholders = Vec::new();
+        parse_snippet(
+            source,
+            false,
+            &resolve_variable,
+            &mut text,
+            &mut tabstops,
+            &mut variable_placeholders,
+        )
+        .context("failed to parse snippet")?;
 
         let len = text.len() as isize;
         let final_tabstop = tabstops.remove(&0);
         let mut tabstops = tabstops.into_values().collect::<Vec<_>>();
+        tabstops.extend(variable_placeholders);
 
         if let Some(final_tabstop) = final_tabstop {
             tabstops.push(final_tabstop);
@@ -37,14 +57,37 @@ impl Snippet {
 fn parse_snippet<'a>(
     mut source: &'a str,
     nested: bool,
+    resolve_variable: &dyn Fn(&str) -> Option<String>,
     text: &mut String,
     tabstops: &mut BTreeMap<usize, TabStop>,
+    variable_placeholders: &mut Vec<TabStop>,
 ) -> Result<&'a str> {
     loop {
         match source.chars().next() {
             None => return Ok(""),
             Some('$') => {
-                source = parse_tabstop(&source[1..], text, tabstops)?;
+                let rest = &source[1..];
+                source = if rest.starts_with(is_variable_start)
+                    || rest
+                        .strip_prefix('{')
+                        .map_or(false, |rest| rest.starts_with(is_variable_start))
+                {
+                    parse_variable(
+                        rest,
+                        resolve_variable,
+                        text,
+                        tabstops,
+                        variable_placeholders,
+                    )?
+                } else {
+                    parse_tabstop(
+                        rest,
+                        resolve_variable,
+                        text,
+                        tabstops,
+                        variable_placeholders,
+                    )?
+                };
             }
             Some('\\') => {
                 source = &source[1..];
@@ -73,8 +116,10 @@ fn parse_snippet<'a>(
 
 fn parse_tabstop<'a>(
     mut source: &'a str,
+    resolve_variable: &dyn Fn(&str) -> Option<String>,
     text: &mut String,
     tabstops: &mut BTreeMap<usize, TabStop>,
+    variable_placeholders: &mut Vec<TabStop>,
 ) -> Result<&'a str> {
     let tabstop_start = text.len();
     let tabstop_index;
@@ -84,7 +129,16 @@ fn parse_tabstop<'a>(
         source = rest;
 
         if source.starts_with(':') {
-            source = parse_snippet(&source[1..], true, text, tabstops)?;
+            source = parse_snippet(
+                &source[1..],
+                true,
+                resolve_variable,
+                text,
+                tabstops,
+                variable_placeholders,
+            )?;
+        } else if source.starts_with('|') {
+            source = parse_choice(&source[1..], text)?;
         }
 
         if source.starts_with('}') {
@@ -105,6 +159,119 @@ fn parse_tabstop<'a>(
     Ok(source)
 }
 
+/// Parses the options of a choice such as `${1|one,two|}`, inserting the first option as the
+/// text of the tabstop.
+fn parse_choice<'a>(mut source: &'a str, text: &mut String) -> Result<&'a str> {
+    let mut is_first_option = true;
+    loop {
+        match source.chars().next() {
+            None => return Err(anyhow!("expected the end of a choice")),
+            Some('|') => return Ok(&source[1..]),
+            Some(',') => {
+                is_first_option = false;
+                source = &source[1..];
+            }
+            Some('\\') => {
+                source = &source[1..];
+                if let Some(c) = source.chars().next() {
+                    if is_first_option {
+                        text.push(c);
+                    }
+                    source = &source[c.len_utf8()..];
+                }
+            }
+            Some(_) => {
+                let chunk_end = source.find([',', '|', '\\']).unwrap_or(source.len());
+                let (chunk, rest) = source.split_at(chunk_end);
+                if is_first_option {
+                    text.push_str(chunk);
+                }
+                source = rest;
+            }
+        }
+    }
+}
+
+fn parse_variable<'a>(
+    mut source: &'a str,
+    resolve_variable: &dyn Fn(&str) -> Option<String>,
+    text: &mut String,
+    tabstops: &mut BTreeMap<usize, TabStop>,
+    variable_placeholders: &mut Vec<TabStop>,
+) -> Result<&'a str> {
+    let braced = source.starts_with('{');
+    if braced {
+        source = &source[1..];
+    }
+
+    let name_len = source
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .unwrap_or(source.len());
+    let (name, rest) = source.split_at(name_len);
+    source = rest;
+    let value = resolve_variable(name);
+
+    if braced {
+        if source.starts_with(':') {
+            source = if let Some(value) = value.as_ref() {
+                // The default isn't used, but it still has to be parsed to find its end.
+                let rest = parse_snippet(
+                    &source[1..],
+                    true,
+                    resolve_variable,
+                    &mut String::new(),
+                    &mut BTreeMap::new(),
+                    &mut Vec::new(),
+                )?;
+                text.push_str(value);
+                rest
+            } else {
+                parse_snippet(
+                    &source[1..],
+                    true,
+                    resolve_variable,
+                    text,
+                    tabstops,
+                    variable_placeholders,
+                )?
+            };
+        } else {
+            push_variable_value(name, value, text, variable_placeholders);
+        }
+
+        if source.starts_with('}') {
+            source = &source[1..];
+        } else {
+            return Err(anyhow!("expected a closing brace"));
+        }
+    } else {
+        push_variable_value(name, value, text, variable_placeholders);
+    }
+
+    Ok(source)
+}
+
+/// Inserts the value of a variable without a default, or its name as a placeholder when it
+/// couldn't be resolved.
+fn push_variable_value(
+    name: &str,
+    value: Option<String>,
+    text: &mut String,
+    variable_placeholders: &mut Vec<TabStop>,
+) {
+    if let Some(value) = value {
+        text.push_str(&value);
+    } else {
+        let start = text.len() as isize;
+        text.push_str(name);
+        variable_placeholders.push([start..text.len() as isize].into_iter().collect());
+    }
+}
+
+fn is_variable_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
 fn parse_int(source: &str) -> Result<(usize, &str)> {
     let len = source
         .find(|c: char| !c.is_ascii_digit())
@@ -199,6 +366,51 @@ mod tests {
         assert_eq!(tabstops(&snippet), &[vec![3..3]]);
     }
 
+    #[test]
+    fn test_snippet_with_choices() {
+        let snippet = Snippet::parse("let x = ${1|one,two,three|};$0").unwrap();
+        assert_eq!(snippet.text, "let x = one;");
+        assert_eq!(tabstops(&snippet), &[vec![8..11], vec![12..12]]);
+
+        let snippet = Snippet::parse("${1|a\\,b,c|}").unwrap();
+        assert_eq!(snippet.text, "a,b");
+        assert_eq!(tabstops(&snippet), &[vec![0..3], vec![3..3]]);
+    }
+
+    #[test]
+    fn test_snippet_with_variables() {
+        let resolve = |name: &str| match name {
+            "TM_FILENAME" => Some("main.rs".to_string()),
+            "TM_LINE_NUMBER" => Some("7".to_string()),
+            _ => None,
+        };
+
+        let snippet =
+            Snippet::parse_with_variables("// $TM_FILENAME:${TM_LINE_NUMBER}$1", resolve).unwrap();
+        assert_eq!(snippet.text, "// main.rs:7");
+        assert_eq!(tabstops(&snippet), &[vec![12..12]]);
+
+        // A resolved variable replaces its default.
+        let snippet =
+            Snippet::parse_with_variables("${TM_FILENAME:${1:untitled}}", resolve).unwrap();
+        assert_eq!(snippet.text, "main.rs");
+        assert_eq!(tabstops(&snippet), &[vec![7..7]]);
+
+        // Unresolved variables use their default, or their name as a placeholder.
+        let snippet =
+            Snippet::parse_with_variables("${CLIPBOARD:${1:text}}-$UNKNOWN-", resolve).unwrap();
+        assert_eq!(snippet.text, "text-UNKNOWN-");
+        assert_eq!(tabstops(&snippet), &[vec![0..4], vec![5..12], vec![13..13]]);
+
+        // Variable placeholders come after numbered tabstops, but before the final one.
+        let snippet = Snippet::parse_with_variables("${UNKNOWN}$2$1$0", resolve).unwrap();
+        assert_eq!(snippet.text, "UNKNOWN");
+        assert_eq!(
+            tabstops(&snippet),
+            &[vec![7..7], vec![7..7], vec![0..7], vec![7..7]]
+        );
+    }
+
     fn tabstops(snippet: &Snippet) -> Vec<Vec<Range<isize>>> {
         snippet.tabstops.iter().map(|t| t.to_vec()).collect()
     }
@@ -662,6 +662,7 @@ impl Element for TerminalElement {
                     underline: None,
                     strikethrough: None,
                     color: theme.colors().text,
+                    ..Default::default()
                 };
 
                 let text_system = cx.text_system();
@@ -35,6 +35,7 @@ impl ElevationIndex {
                 offset: point(px(0.), px(2.)),
                 blur_radius: px(3.),
                 spread_radius: px(0.),
+                ..Default::default()
             }],
 
             ElevationIndex::ModalSurface => smallvec![
@@ -43,18 +44,21 @@ impl ElevationIndex {
                     offset: point(px(0.), px(2.)),
                     blur_radius: px(3.),
                     spread_radius: px(0.),
+                    ..Default::default()
                 },
                 BoxShadow {
                     color: hsla(0., 0., 0., 0.08),
                     offset: point(px(0.), px(3.)),
                     blur_radius: px(6.),
                     spread_radius: px(0.),
+                    ..Default::default()
                 },
                 BoxShadow {
                     color: hsla(0., 0., 0., 0.04),
                     offset: point(px(0.), px(6.)),
                     blur_radius: px(12.),
                     spread_radius: px(0.),
+                    ..Default::default()
                 },
             ],
 
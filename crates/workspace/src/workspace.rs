@@ -30,10 +30,10 @@ use gpui::{
     action_as, actions, canvas, impl_action_as, impl_actions, point, relative, size,
     transparent_black, Action, AnyElement, AnyView, AnyWeakView, AppContext, AsyncAppContext,
     AsyncWindowContext, Bounds, CursorStyle, Decorations, DragMoveEvent, Entity as _, EntityId,
-    EventEmitter, FocusHandle, FocusableView, Global, Hsla, KeyContext, Keystroke, ManagedView,
-    Model, ModelContext, MouseButton, PathPromptOptions, Point, PromptLevel, Render, ResizeEdge,
-    Size, Stateful, Subscription, Task, Tiling, View, WeakView, WindowBounds, WindowHandle,
-    WindowOptions,
+    EventEmitter, FocusHandle, FocusableView, Global, Hsla, InspectorStyle, KeyContext, Keystroke,
+    ManagedView, Model, ModelContext, MouseButton, PathPromptOptions, Point, PromptLevel, Render,
+    ResizeEdge, Size, Stateful, Subscription, Task, Tiling, View, WeakView, WindowBounds,
+    WindowHandle, WindowOptions,
 };
 use item::{
     FollowableItem, FollowableItemHandle, Item, ItemHandle, ItemSettings, PreviewTabsSettings,
@@ -121,6 +121,8 @@ actions!(
         CloseAllDocks,
         CloseWindow,
         Feedback,
+        FocusNext,
+        FocusPrev,
         FollowNextCollaborator,
         NewCenterTerminal,
         NewFile,
@@ -134,6 +136,7 @@ actions!(
         SaveWithoutFormat,
         ToggleBottomDock,
         ToggleCenteredLayout,
+        ToggleInspector,
         ToggleLeftDock,
         ToggleRightDock,
         ToggleZoom,
@@ -3911,6 +3914,9 @@ impl Workspace {
                 }),
             )
             .on_action(cx.listener(Workspace::toggle_centered_layout))
+            .on_action(cx.listener(Workspace::toggle_inspector))
+            .on_action(cx.listener(|_, _: &FocusNext, cx| cx.focus_next()))
+            .on_action(cx.listener(|_, _: &FocusPrev, cx| cx.focus_prev()))
     }
 
     #[cfg(any(test, feature = "test-support"))]
@@ -3990,6 +3996,19 @@ impl Workspace {
         cx.notify();
     }
 
+    fn toggle_inspector(&mut self, _: &ToggleInspector, cx: &mut ViewContext<Self>) {
+        let colors = cx.theme().colors();
+        let style = InspectorStyle {
+            panel_background: colors.elevated_surface_background,
+            text_color: colors.text,
+            highlight_fill: colors.border_focused.opacity(0.2),
+            highlight_border: colors.border_focused,
+            ..Default::default()
+        };
+        cx.set_inspector_style(style);
+        cx.toggle_inspector();
+    }
+
     fn adjust_padding(padding: Option<f32>) -> f32 {
         padding
             .unwrap_or(Self::DEFAULT_PADDING)
@@ -6588,6 +6607,7 @@ pub fn client_side_decorations(element: impl IntoElement, cx: &mut WindowContext
                                 blur_radius: theme::CLIENT_SIDE_DECORATION_SHADOW / 2.,
                                 spread_radius: px(0.),
                                 offset: point(px(0.0), px(0.0)),
+                                ..Default::default()
                             }])
                         }),
                 })
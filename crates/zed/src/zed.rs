@@ -3120,6 +3120,65 @@ mod tests {
         );
     }
 
+    #[gpui::test]
+    async fn test_tab_outside_tab_navigation(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        cx.update(|cx| KeymapFile::load_asset(DEFAULT_KEYMAP_PATH, cx).unwrap());
+        cx.update(|cx| {
+            open_new(app_state.clone(), cx, |workspace, cx| {
+                Editor::new_file(workspace, &Default::default(), cx)
+            })
+        })
+        .await
+        .unwrap();
+        cx.run_until_parked();
+
+        let workspace = cx
+            .update(|cx| cx.windows().first().unwrap().downcast::<Workspace>())
+            .unwrap();
+        let (editor, active_pane) = workspace
+            .update(cx, |workspace, cx| {
+                let editor = workspace
+                    .active_item(cx)
+                    .unwrap()
+                    .downcast::<Editor>()
+                    .unwrap();
+                (editor, workspace.active_pane().clone())
+            })
+            .unwrap();
+        let cx = &mut VisualTestContext::from_window(workspace.into(), cx);
+        cx.update(|cx| cx.focus_view(&editor_focus));
+
+        // Tab is handled by the editor instead of moving focus.
+        cx.simulate_keystrokes("tab");
+        editor.update(cx, |editor, cx| {
+            assert_eq!(editor.text(cx), "    ");
+            assert!(editor.focus_handle(cx).is_focused(cx));
+        });
+
+        // Views without their own tab binding, like the terminal, receive tab as a key event.
+        cx.update(|cx| {
+            let editor_focus = editor.focus_handle(cx);
+            assert!(!cx
+                .bindings_for_action_in(&editor::actions::Tab, &editor_focus)
+                .is_empty());
+            assert!(cx
+                .bindings_for_action_in(&workspace::FocusNext, &editor_focus)
+                .is_empty());
+
+            let pane_focus = active_pane.focus_handle(cx);
+            assert!(!cx
+                .bindings_for_action_in(&pane::ActivateNextItem, &pane_focus)
+                .is_empty());
+            assert!(cx
+                .bindings_for_action_in(&workspace::FocusNext, &pane_focus)
+                .is_empty());
+            assert!(cx
+                .bindings_for_action_in(&workspace::FocusPrev, &pane_focus)
+                .is_empty());
+        });
+    }
+
     #[gpui::test]
     fn test_bundled_settings_and_themes(cx: &mut AppContext) {
         cx.text_system()